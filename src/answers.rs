@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::solution::{Output, Part};
+
+/// Answers of one day, keyed by part and input hash.
+type DayAnswers = BTreeMap<String, BTreeMap<String, String>>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = toml::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, content)
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: Part, input_hash: &str) -> Option<&str> {
+        self.years.get(&year.to_string())
+            .and_then(|days| days.get(&day_key(day)))
+            .and_then(|parts| parts.get(&part.to_string()))
+            .and_then(|inputs| inputs.get(input_hash))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, year: u16, day: usize, part: Part, input_hash: &str, output: &Output) -> Status {
+        match self.get(year, day, part, input_hash) {
+            None => Status::Unknown,
+            Some(expected) if expected == output.to_string() => Status::Pass,
+            Some(expected) => Status::Fail { expected: expected.to_string() },
+        }
+    }
+
+    pub fn record(&mut self, year: u16, day: usize, part: Part, input_hash: &str, output: &Output) {
+        self.years.entry(year.to_string()).or_default()
+            .entry(day_key(day)).or_default()
+            .entry(part.to_string()).or_default()
+            .insert(input_hash.to_string(), output.to_string());
+    }
+}
+
+fn day_key(day: usize) -> String {
+    format!("day_{}", day)
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{Output, Part};
+    use super::{Answers, Status};
+
+    #[test]
+    fn test_check_and_record() {
+        let mut answers = Answers::default();
+        assert_eq!(Status::Unknown, answers.check(2021, 1, Part::A, "abc", &Output::from(7usize)));
+
+        answers.record(2021, 1, Part::A, "abc", &Output::from(7usize));
+        assert_eq!(Status::Pass, answers.check(2021, 1, Part::A, "abc", &Output::from(7usize)));
+        assert_eq!(Status::Fail { expected: "7".to_string() }, answers.check(2021, 1, Part::A, "abc", &Output::from(8usize)));
+        assert_eq!(Status::Unknown, answers.check(2021, 1, Part::B, "abc", &Output::from(7usize)));
+        assert_eq!(Status::Unknown, answers.check(2021, 1, Part::A, "def", &Output::from(7usize)));
+        assert_eq!(Status::Unknown, answers.check(2022, 1, Part::A, "abc", &Output::from(7usize)));
+
+        let reloaded: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(Some("7"), reloaded.get(2021, 1, Part::A, "abc"));
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage { ParseLines, Parse, PartA, PartB }
+
+pub struct Config {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: usize,
+    pub stage: Stage,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Stage::ParseLines => "parse lines",
+            Stage::Parse => "parse",
+            Stage::PartA => "part a",
+            Stage::PartB => "part b",
+        })
+    }
+}
+
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Timing {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let mut samples = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    samples.sort();
+    Timing {
+        min: samples[0],
+        median: samples[samples.len() / 2],
+        max: samples[samples.len() - 1],
+    }
+}
+
+impl Measurement {
+    pub fn new(year: u16, day: usize, stage: Stage, timing: Timing) -> Self {
+        Measurement {
+            year,
+            day,
+            stage,
+            min_ns: timing.min.as_nanos() as u64,
+            median_ns: timing.median.as_nanos() as u64,
+            max_ns: timing.max.as_nanos() as u64,
+        }
+    }
+
+    pub fn timing(&self) -> Timing {
+        Timing {
+            min: Duration::from_nanos(self.min_ns),
+            median: Duration::from_nanos(self.median_ns),
+            max: Duration::from_nanos(self.max_ns),
+        }
+    }
+
+    /// Relative change of the median against the same puzzle and stage of a previous run.
+    pub fn change_since(&self, previous: &[Measurement]) -> Option<f64> {
+        previous.iter()
+            .find(|p| p.year == self.year && p.day == self.day && p.stage == self.stage && p.median_ns > 0)
+            .map(|p| self.median_ns as f64 / p.median_ns as f64 - 1.0)
+    }
+}
+
+pub fn save(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+    fs::write(path, serde_json::to_string_pretty(measurements)?)
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<Measurement>> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{Config, measure, Measurement, Stage, Timing};
+
+    #[test]
+    fn test_measure_orders_samples() {
+        let mut calls = 0;
+        let timing = measure(&Config { warmup: 2, samples: 5 }, || calls += 1);
+        assert_eq!(7, calls);
+        assert!(timing.min <= timing.median && timing.median <= timing.max);
+    }
+
+    #[test]
+    fn test_change_since() {
+        let timing = |ms| Timing { min: Duration::from_millis(ms), median: Duration::from_millis(ms), max: Duration::from_millis(ms) };
+        let previous = vec![Measurement::new(2021, 1, Stage::PartA, timing(100))];
+        let slower = Measurement::new(2021, 1, Stage::PartA, timing(125));
+        assert_eq!(Some(0.25), slower.change_since(&previous));
+        assert_eq!(None, Measurement::new(2021, 1, Stage::PartB, timing(125)).change_since(&previous));
+        assert_eq!(slower, serde_json::from_str(&serde_json::to_string(&slower).unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,112 @@
+use std::any::type_name;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Io { file: String, message: String },
+    InvalidToken { file: Option<String>, line: usize, column: usize, token: String, expected: String },
+    Missing { file: Option<String>, line: usize, column: usize, expected: String },
+}
+
+impl ParseError {
+    pub fn invalid(line: usize, text: &str, token: &str, expected: &str) -> Self {
+        ParseError::InvalidToken {
+            file: None,
+            line,
+            column: column_of(text, token),
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn missing(line: usize, text: &str, expected: &str) -> Self {
+        ParseError::Missing {
+            file: None,
+            line,
+            column: text.chars().count() + 1,
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn in_file(self, file: impl Display) -> Self {
+        let name = Some(file.to_string());
+        match self {
+            ParseError::Io { .. } => self,
+            ParseError::InvalidToken { line, column, token, expected, .. } =>
+                ParseError::InvalidToken { file: name, line, column, token, expected },
+            ParseError::Missing { line, column, expected, .. } =>
+                ParseError::Missing { file: name, line, column, expected },
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Io { file, message } =>
+                write!(f, "{}: {}", file, message),
+            ParseError::InvalidToken { file, line, column, token, expected } =>
+                write!(f, "{}:{}:{}: expected {}, found '{}'", file.as_deref().unwrap_or("<input>"), line, column, expected, token),
+            ParseError::Missing { file, line, column, expected } =>
+                write!(f, "{}:{}:{}: missing {}", file.as_deref().unwrap_or("<input>"), line, column, expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_token<T: FromStr>(line: usize, text: &str, token: &str) -> Result<T, ParseError> {
+    token.parse::<T>().map_err(|_| ParseError::invalid(line, text, token, type_name::<T>()))
+}
+
+pub fn expect_token<'a>(line: usize, text: &'a str, token: Option<&'a str>, expected: &str) -> Result<&'a str, ParseError> {
+    token.ok_or_else(|| ParseError::missing(line, text, expected))
+}
+
+/// The column where `token` starts, by its address if it is a slice of `text` and else by
+/// searching `text` for it.
+fn column_of(text: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    let inside = offset.checked_add(token.len()).is_some_and(|end| end <= text.len()) && text.is_char_boundary(offset);
+    let offset = if inside { offset } else { text.find(token).unwrap_or(0) };
+    text[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_token, ParseError};
+
+    #[test]
+    fn test_invalid_token_location() {
+        let text = "22 13 x1 7";
+        let token = text.split_whitespace().nth(2).unwrap();
+        let error = parse_token::<usize>(3, text, token).unwrap_err()
+            .in_file("input/2021/day_4/input.txt");
+        assert_eq!(ParseError::InvalidToken {
+            file: Some("input/2021/day_4/input.txt".to_string()),
+            line: 3,
+            column: 7,
+            token: "x1".to_string(),
+            expected: "usize".to_string(),
+        }, error);
+        assert_eq!("input/2021/day_4/input.txt:3:7: expected usize, found 'x1'", error.to_string());
+    }
+
+    #[test]
+    fn test_token_elsewhere() {
+        let text = "ä 13 x1";
+        let copy = String::from("x1");
+        assert_eq!("<input>:1:6: expected usize, found 'x1'", parse_token::<usize>(1, text, &copy).unwrap_err().to_string());
+        // an empty token whose address is inside the first character of the text
+        let bytes = text.as_bytes();
+        let inside = std::str::from_utf8(&bytes[1..1]).unwrap();
+        assert_eq!("<input>:1:1: expected usize, found ''", parse_token::<usize>(1, text, inside).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let error = ParseError::missing(2, "forward", "a magnitude");
+        assert_eq!("<input>:2:8: missing a magnitude", error.to_string());
+    }
+}
@@ -0,0 +1,103 @@
+use std::ops::RangeInclusive;
+
+/// Generates random puzzle inputs that respect the guarantees of the puzzle description, so the
+/// solvers can be benchmarked and fuzzed on more and larger inputs than the published one.
+pub trait Generate {
+    /// Size of a real puzzle input. What the size counts, e.g. lines or the side of a grid, is
+    /// documented per day.
+    const SIZE: usize;
+
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A small seeded random number generator (SplitMix64). Its output only depends on the seed, so
+/// a generated input can be reproduced from the seed on any platform and version of this crate.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (from, to) = range.into_inner();
+        assert!(from <= to, "empty range");
+        from + ((self.next_u64() as u128 * ((to - from) as u128 + 1)) >> 64) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use super::Rng;
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| numbers(7).contains(&n)));
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        Rng::new(1).shuffle(&mut items);
+        items.sort();
+        assert_eq!((0..10).collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for puzzle in registry::puzzles() {
+            for (seed, size) in [(0, 1), (1, 4), (2, 8)] {
+                let text = (puzzle.generate)(seed, Some(size));
+                assert_eq!(text, (puzzle.generate)(seed, Some(size)));
+                if let Err(e) = (puzzle.validate)(&text) {
+                    panic!("{} day {} seed {} size {}: {}\n{}", puzzle.year, puzzle.day, seed, size, e, text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        // day 23 takes too long without optimizations, see its ignored example test
+        for puzzle in registry::puzzles().into_iter().filter(|p| (p.year, p.day) != (2021, 23)) {
+            for seed in 0..3 {
+                let text = (puzzle.generate)(seed, Some(5));
+                assert!((puzzle.solve)(&text, &crate::Part::all()).is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::error::ParseError;
+
+/// Column and row of a cell.
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// Builds a grid from its cells in row-major order. Panics if they do not fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells do not fill rows of {}", cells.len(), width);
+        Grid { width, height: cells.len() / width, cells }
+    }
+
+    /// Parses one cell per character, requiring at least one row and all rows to be equally long.
+    pub fn parse<S: AsRef<str>>(lines: impl Iterator<Item=S>, expected: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_numbered((1..).zip(lines), expected, cell)
+    }
+
+    /// Like [`Grid::parse`], for lines that do not start at the top of the input.
+    pub fn parse_numbered<S: AsRef<str>>(lines: impl Iterator<Item=(usize, S)>, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut last_line = 0;
+        for (n, line) in lines {
+            let line = line.as_ref();
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::invalid(n, line, &line[i..i + c.len_utf8()], expected))?;
+                if width == Some(cells.len() - row_start) {
+                    return Err(ParseError::invalid(n, line, &line[i..], &format!("{} cells per row", cells.len() - row_start)));
+                }
+                cells.push(value);
+            }
+            match width {
+                Some(width) if cells.len() - row_start < width =>
+                    return Err(ParseError::missing(n, line, &format!("{} cells per row", width))),
+                None if cells.len() == row_start =>
+                    return Err(ParseError::missing(n, line, expected)),
+                _ => width = Some(cells.len() - row_start),
+            }
+            last_line = n;
+        }
+        match width {
+            Some(width) => Ok(Grid::from_cells(width, cells)),
+            None => Err(ParseError::missing(last_line + 1, "", "a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Position of the cell at `x`, `y` when the grid repeats endlessly in every direction.
+    pub fn wrap(&self, x: isize, y: isize) -> Position {
+        (x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)
+    }
+
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The up to four orthogonally adjacent positions inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item=Position> + '_ {
+        NEIGHBOURS_4.iter().filter_map(move |&d| self.offset(position, d))
+    }
+
+    /// The up to eight orthogonally and diagonally adjacent positions inside the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item=Position> + '_ {
+        NEIGHBOURS_8.iter().filter_map(move |&d| self.offset(position, d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item=Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item=&mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Position) -> &Self::Output {
+        assert!(x < self.width && y < self.height, "position {:?} outside of {}x{} grid", (x, y), self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "position {:?} outside of {}x{} grid", (x, y), self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use crate::error::ParseError;
+    use super::Grid;
+
+    fn digits(lines: &[&str]) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(lines.iter().map(|l| l.to_string()), "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits(&["123", "456"]).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!("123\n456", grid.to_string());
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], grid.columns().map(|c| c.copied().collect_vec()).collect_vec());
+        assert_eq!(vec![&[4, 5, 6][..]], grid.rows().skip(1).collect_vec());
+
+        assert_eq!("<input>:2:3: expected a digit, found 'x'", digits(&["123", "45x"]).unwrap_err().to_string());
+        assert_eq!("<input>:2:3: missing 3 cells per row", digits(&["123", "45"]).unwrap_err().to_string());
+        assert_eq!("<input>:2:4: expected 3 cells per row, found '78'", digits(&["123", "45678"]).unwrap_err().to_string());
+        assert_eq!("<input>:1:1: missing a grid", digits(&[]).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_neighbours_and_wrapping() {
+        let grid = Grid::from_fn(3, 3, |(x, y)| x + 3 * y);
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours_4((0, 0)).collect_vec());
+        assert_eq!(4, grid.neighbours_4((1, 1)).count());
+        assert_eq!(3, grid.neighbours_8((2, 2)).count());
+        assert_eq!(8, grid.neighbours_8((1, 1)).count());
+        assert_eq!((2, 0), grid.wrap(-1, 3));
+        assert_eq!(&8, grid.get_wrapping(5, -1));
+    }
+}
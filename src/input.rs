@@ -0,0 +1,215 @@
+use std::{fs, io, vec};
+use std::any::type_name;
+use std::fmt::{Display, Formatter};
+use std::iter::once;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::error::{parse_token, ParseError};
+use crate::store::{InputStore, Key};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+/// A line borrowed from the whole input, together with its number counted from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl Source {
+    pub fn for_day(year: u16, day: usize, input: Option<&Path>) -> Source {
+        match input {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_path_buf()),
+            None => Source::File(default_input_path(year, day)),
+        }
+    }
+
+    pub fn read_lines(&self) -> Result<vec::IntoIter<String>, ParseError> {
+        self.read_text().map(split_lines)
+    }
+
+    pub fn read_text(&self) -> Result<String, ParseError> {
+        match self {
+            Source::Stdin => io::read_to_string(io::stdin())
+                .map_err(|e| ParseError::Io { file: self.to_string(), message: e.to_string() }),
+            Source::File(path) => read_text(path),
+        }
+    }
+}
+
+impl<'a> Line<'a> {
+    /// The parts of the line between `separator`s, skipping empty ones so that runs of spaces
+    /// count as one separator.
+    pub fn fields(self, separator: char) -> impl Iterator<Item=&'a str> {
+        self.text.split(separator).filter(|field| !field.is_empty())
+    }
+
+    pub fn field(self, field: Option<&'a str>, expected: &str) -> Result<&'a str, ParseError> {
+        field.ok_or_else(|| self.missing(expected))
+    }
+
+    pub fn parse<T: FromStr>(self, token: &str) -> Result<T, ParseError> {
+        parse_token(self.number, self.text, token)
+    }
+
+    /// Parses a decimal integer like [`Line::parse`], but without going through `str::parse`.
+    pub fn int<T: TryFrom<i64>>(self, token: &str) -> Result<T, ParseError> {
+        scan_int(token)
+            .and_then(|n| T::try_from(n).ok())
+            .ok_or_else(|| self.invalid(token, type_name::<T>()))
+    }
+
+    /// All integers of the line, separated by anything that is neither a digit nor a minus sign.
+    pub fn ints<T: TryFrom<i64>>(self) -> impl Iterator<Item=Result<T, ParseError>> + 'a {
+        self.text.split(|c: char| !c.is_ascii_digit() && c != '-')
+            .filter(|token| !token.is_empty())
+            .map(move |token| self.int(token))
+    }
+
+    pub fn invalid(self, token: &str, expected: &str) -> ParseError {
+        ParseError::invalid(self.number, self.text, token, expected)
+    }
+
+    pub fn missing(self, expected: &str) -> ParseError {
+        ParseError::missing(self.number, self.text, expected)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn default_input_path(year: u16, day: usize) -> PathBuf {
+    InputStore::from_env().path(&Key::input(year, day))
+}
+
+pub fn read_lines<P>(filename: P) -> Result<vec::IntoIter<String>, ParseError>
+    where P: AsRef<Path>, {
+    read_text(filename).map(split_lines)
+}
+
+pub fn read_text<P>(filename: P) -> Result<String, ParseError>
+    where P: AsRef<Path>, {
+    let path = filename.as_ref();
+    fs::read_to_string(path).map_err(|e| ParseError::Io {
+        file: path.display().to_string(),
+        message: e.to_string(),
+    })
+}
+
+fn split_lines(content: String) -> vec::IntoIter<String> {
+    content.lines().map(str::to_string).collect_vec().into_iter()
+}
+
+/// Numbered lines of the whole input, borrowed from it.
+pub fn lines(text: &str) -> impl Iterator<Item=Line<'_>> {
+    (1..).zip(text.lines()).map(|(number, text)| Line { number, text })
+}
+
+/// Joins lines back into one text, for the default [`Solution::parse`] of days that parse the whole
+/// input in [`Solution::parse_text`].
+///
+/// [`Solution::parse`]: crate::solution::Solution::parse
+/// [`Solution::parse_text`]: crate::solution::Solution::parse_text
+pub fn join_lines(lines: impl Iterator<Item=String>) -> String {
+    lines.fold(String::new(), |mut text, line| {
+        text.push_str(&line);
+        text.push('\n');
+        text
+    })
+}
+
+/// Parses an optionally negative decimal integer byte by byte, returning `None` on anything else
+/// or on overflow.
+pub fn scan_int(token: &str) -> Option<i64> {
+    let (negative, digits) = match token.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    // negative numbers are accumulated downwards, as i64::MIN has no positive counterpart
+    let sign = if negative { -1 } else { 1 };
+    digits.iter().try_fold(0i64, |n, &b| match b {
+        b'0'..=b'9' => n.checked_mul(10)?.checked_add(sign * (b - b'0') as i64),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+pub fn example_lines(example: &str) -> vec::IntoIter<String> {
+    split_lines(example.to_string())
+}
+
+/// Parses an input that a test writes line by line, e.g. from generated values.
+#[cfg(test)]
+pub fn parse_example<S: crate::solution::Solution>(lines: impl IntoIterator<Item=String>) -> S::Input {
+    S::parse(lines.into_iter()).expect("the test writes a valid input")
+}
+
+pub fn input_hash(text: &str) -> String {
+    // FNV-1a, so the hash stays stable across platforms and Rust releases
+    let hash = text.lines()
+        .flat_map(|line| line.bytes().chain(once(b'\n')))
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+pub fn read_usize_vec(lines: &mut impl Iterator<Item=String>) -> Result<Vec<usize>, ParseError> {
+    let line = lines.next().ok_or_else(|| ParseError::missing(1, "", "a comma separated list"))?;
+    line.split(',')
+        .map(|s| parse_token(1, &line, s))
+        .collect()
+}
+
+pub fn parse_digits(line: usize, text: &str, radix: u32) -> Result<Vec<usize>, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| c.to_digit(radix)
+            .map(|d| d as usize)
+            .ok_or_else(|| ParseError::invalid(line, text, &text[i..i + c.len_utf8()], "a digit")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join_lines, lines, scan_int, Line};
+
+    #[test]
+    fn test_scan_int() {
+        assert_eq!(Some(1234), scan_int("1234"));
+        assert_eq!(Some(-56), scan_int("-56"));
+        assert_eq!(Some(7), scan_int("+7"));
+        assert_eq!(None, scan_int("-"));
+        assert_eq!(None, scan_int("12a"));
+        assert_eq!(None, scan_int("99999999999999999999"));
+        assert_eq!(Some(i64::MAX), scan_int("9223372036854775807"));
+        assert_eq!(Some(i64::MIN), scan_int("-9223372036854775808"));
+        assert_eq!(None, scan_int("9223372036854775808"));
+        assert_eq!(None, scan_int("-9223372036854775809"));
+    }
+
+    #[test]
+    fn test_lines_and_fields() {
+        let text = join_lines(["on x=-5..10,y=3..4", "7  8 x"].iter().map(|l| l.to_string()));
+        let all = lines(&text).collect::<Vec<_>>();
+        assert_eq!(Line { number: 2, text: "7  8 x" }, all[1]);
+        assert_eq!(vec![-5, 10, 3, 4], all[0].ints::<isize>().collect::<Result<Vec<_>, _>>().unwrap());
+        assert_eq!(vec!["7", "8", "x"], all[1].fields(' ').collect::<Vec<_>>());
+        assert_eq!("<input>:2:6: expected u8, found 'x'", all[1].fields(' ').map(|f| all[1].int::<u8>(f)).nth(2).unwrap().unwrap_err().to_string());
+        assert_eq!("<input>:1:19: missing a cuboid", all[0].field(None, "a cuboid").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,16 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod generator;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod store;
+pub mod watch;
+pub mod y2021;
+
+pub use error::ParseError;
+pub use solution::{Output, Part, Solution};
@@ -0,0 +1,630 @@
+use std::cell::{Cell, RefCell};
+use std::io::{self, IsTerminal, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::{Duration, Instant};
+use clap::{Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde::Serialize;
+use tracing::debug_span;
+use tracing_subscriber::EnvFilter;
+use adventofcode::answers::{Answers, Status};
+use adventofcode::bench::{self, Config, Measurement, Stage};
+use adventofcode::input::{input_hash, Source};
+use adventofcode::registry::{self, Puzzle, Solved};
+use adventofcode::scaffold;
+use adventofcode::store::{HttpFetch, InputStore, Key};
+use adventofcode::watch::{self, Tests, Watch};
+use adventofcode::Part;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solvers do to stderr, -v for debug and -vv for trace output
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Only log what matches these filter directives, e.g. 'adventofcode::y2021::day_19=debug'.
+    /// Defaults to $AOC_LOG
+    #[arg(long, global = true)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of a single day, of all days of a year or of all years
+    Run {
+        /// Year of the puzzle
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+        /// Day to solve (1-25)
+        #[arg(required_unless_present = "all")]
+        day: Option<usize>,
+        /// Only solve the given part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file, or from stdin if it is '-'.
+        /// Defaults to the input of the day in the store at $AOC_INPUT_DIR or input/
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Solve all days of the year, or of all years if no year is given, using their default input
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Solve the days of --all concurrently on this many threads (0 for one per core) and
+        /// finish with a table sorted by year, day and part
+        #[arg(long, requires = "all")]
+        jobs: Option<usize>,
+        /// File with the known answers per year, day, part and input
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Store the computed answers instead of checking them
+        #[arg(long)]
+        record: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Measure parse and solve times of the given days, of all days of a year or of all years
+    Bench {
+        /// Year of the puzzles
+        year: Option<u16>,
+        /// Days to benchmark (1-25)
+        days: Vec<usize>,
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs per stage
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Benchmark a single day on this input file, or on stdin if it is '-', e.g. on one made by `aoc gen`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Save the results as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against results saved by a previous run
+        #[arg(long)]
+        compare: Option<PathBuf>,
+        /// Slowdown of the median in percent that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Validate an input file and add it to the input store
+    Import {
+        /// Year of the puzzle
+        year: u16,
+        /// Day the input belongs to (1-25)
+        day: usize,
+        /// File to import, or '-' for stdin
+        file: PathBuf,
+        /// Kind of input, e.g. 'input' for the puzzle input or 'example' for a worked example
+        #[arg(long, default_value = "input")]
+        variant: String,
+        /// Replace an existing file with different content
+        #[arg(long)]
+        force: bool,
+        /// Skip parsing the file with the day's solver
+        #[arg(long)]
+        no_validate: bool,
+    },
+    /// Generate the module of a new day, register it and add an empty input to the store
+    New {
+        /// Year of the puzzle
+        year: u16,
+        /// Day to generate (1-25)
+        day: usize,
+        /// Root directory of this crate
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Generate a random input for a day, e.g. a larger one for benchmarking
+    Gen {
+        /// Year of the puzzle
+        year: u16,
+        /// Day to generate an input for (1-25)
+        day: usize,
+        /// Size of the input, such as the number of lines or the side of a grid depending on the day.
+        /// Defaults to the size of a real puzzle input
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed of the random number generator. The same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Download the puzzle input of a day into the input store unless it is already there
+    Fetch {
+        /// Year of the puzzle
+        year: u16,
+        /// Day to fetch (1-25)
+        day: usize,
+        /// Server to fetch from. Defaults to $AOC_URL or https://adventofcode.com
+        #[arg(long)]
+        url: Option<String>,
+        /// Fetch again even if the input is already in the store
+        #[arg(long)]
+        force: bool,
+    },
+    /// Re-run the tests and the solver of a day whenever its source file or input changes
+    Watch {
+        /// Year of the puzzle
+        year: u16,
+        /// Day to watch (1-25)
+        day: usize,
+        /// Solve this input file instead of the input of the day in the store
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Root directory of this crate
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format { Text, Json, Csv }
+
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: usize,
+    part: String,
+    answer: Option<String>,
+    elapsed_ms: Option<f64>,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose, cli.log);
+    match cli.command {
+        Command::Run { year, day, part, input, all, jobs, answers: answers_path, record, format } => {
+            let puzzles = match (all, year, day) {
+                (true, year, _) => find_puzzles(year, &[]),
+                (false, Some(year), Some(day)) => find_puzzles(Some(year), &[day]),
+                _ => unreachable!("clap requires a year and day unless --all is given"),
+            };
+            let mut answers = Answers::load(&answers_path).unwrap_or_else(|e| {
+                eprintln!("error: {}: {}", answers_path.display(), e);
+                exit(1);
+            });
+            // panics of solvers become errors of their day, any other panic is reported as usual
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| if SOLVING.get() {
+                PANIC.with(|panic| *panic.borrow_mut() = Some(info.to_string().replace(":\n", ": ")));
+            } else {
+                default_hook(info);
+            }));
+            if format == Format::Csv {
+                println!("year,day,part,answer,elapsed_ms,status,expected,error");
+            }
+            let mut all_records = vec![];
+            if let Some(jobs) = jobs {
+                let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build().unwrap_or_else(|e| {
+                    eprintln!("error: {}", e);
+                    exit(1);
+                });
+                let start = Instant::now();
+                let solved = pool.install(|| puzzles.par_iter().map(|puzzle| solve(puzzle, part, None, true)).collect::<Vec<_>>());
+                let wall_time = start.elapsed();
+                for attempt in &solved {
+                    eprint!("{}", attempt.log);
+                }
+                all_records = puzzles.iter().zip(solved)
+                    .flat_map(|(puzzle, solved)| records(puzzle, solved, &mut answers, record))
+                    .sorted_by(|a, b| (a.year, a.day, &a.part).cmp(&(b.year, b.day, &b.part)))
+                    .collect();
+                match format {
+                    Format::Text => print_table(&all_records, wall_time),
+                    Format::Csv => print_csv(&all_records),
+                    Format::Json => {}
+                }
+            } else {
+                for puzzle in &puzzles {
+                    let records = records(puzzle, solve(puzzle, part, input.as_deref(), false), &mut answers, record);
+                    match format {
+                        Format::Text => print_text(&records),
+                        Format::Csv => print_csv(&records),
+                        Format::Json => {}
+                    }
+                    all_records.extend(records);
+                }
+            }
+            if format == Format::Json {
+                println!("{}", serde_json::to_string_pretty(&all_records).expect("records are serializable"));
+            }
+            let failed = all_records.iter()
+                .filter(|r| r.status == "FAIL" || r.status == "ERROR")
+                .count();
+            if record {
+                if let Err(e) = answers.save(&answers_path) {
+                    eprintln!("error: {}: {}", answers_path.display(), e);
+                    exit(1);
+                }
+            }
+            if failed > 0 {
+                exit(1);
+            }
+        }
+        Command::Bench { year, days, warmup, samples, input, save, compare, threshold } => {
+            let puzzles = find_puzzles(year, &days);
+            if input.is_some() && puzzles.len() != 1 {
+                eprintln!("error: --input needs a single day to benchmark");
+                exit(1);
+            }
+            let previous = compare.map(|path| bench::load(&path).unwrap_or_else(|e| {
+                eprintln!("error: {}: {}", path.display(), e);
+                exit(1);
+            }));
+            let config = Config { warmup, samples };
+            let measurements = puzzles.iter()
+                .flat_map(|puzzle| run_bench(puzzle, &config, input.as_deref()))
+                .collect::<Vec<_>>();
+            let regressions = report_bench(&measurements, previous.as_deref(), threshold / 100.0);
+            if let Some(path) = save {
+                if let Err(e) = bench::save(&path, &measurements) {
+                    eprintln!("error: {}: {}", path.display(), e);
+                    exit(1);
+                }
+            }
+            if regressions > 0 {
+                exit(1);
+            }
+        }
+        Command::Import { year, day, file, variant, force, no_validate } => {
+            let puzzle = &find_puzzles(Some(year), &[day])[0];
+            let content = if file == Path::new("-") { io::read_to_string(io::stdin()) } else { std::fs::read_to_string(&file) };
+            let content = content.unwrap_or_else(|e| {
+                eprintln!("error: {}: {}", file.display(), e);
+                exit(1);
+            });
+            let key = Key::new(year, day, &variant);
+            let result = InputStore::from_env().import(&key, &content, |text| {
+                if no_validate { Ok(()) } else { (puzzle.validate)(text).map_err(|e| e.in_file(file.display())) }
+            }, force);
+            match result {
+                Ok(path) => println!("imported {} to {}", key, path.display()),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    exit(1);
+                }
+            }
+        }
+        Command::New { year, day, root } => {
+            if year < 2015 || !(1..=25).contains(&day) {
+                eprintln!("error: there is no puzzle for {} day {}", year, day);
+                exit(1);
+            }
+            match scaffold::new_day(&root, &InputStore::from_env(), year, day) {
+                Ok(created) => created.iter().for_each(|path| println!("created {}", path.display())),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    exit(1);
+                }
+            }
+        }
+        Command::Gen { year, day, size, seed, output } => {
+            let puzzle = &find_puzzles(Some(year), &[day])[0];
+            let text = (puzzle.generate)(seed, size);
+            match output {
+                Some(path) => if let Err(e) = std::fs::write(&path, text) {
+                    eprintln!("error: {}: {}", path.display(), e);
+                    exit(1);
+                },
+                None => print!("{}", text),
+            }
+        }
+        Command::Fetch { year, day, url, force } => {
+            let puzzle = &find_puzzles(Some(year), &[day])[0];
+            let mut fetch = HttpFetch::from_env();
+            if let Some(url) = url {
+                fetch.base_url = url.trim_end_matches('/').to_string();
+            }
+            let key = Key::input(year, day);
+            match InputStore::from_env().fetch(&key, &fetch, puzzle.validate, force) {
+                Ok(path) => println!("{} is in {}", key, path.display()),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    exit(1);
+                }
+            }
+        }
+        Command::Watch { year, day, input, root } => {
+            find_puzzles(Some(year), &[day]);
+            let input = input.unwrap_or_else(|| InputStore::from_env().path(&Key::input(year, day)));
+            let input = std::path::absolute(&input).unwrap_or(input);
+            if let Err(e) = watch_day(&Watch { root, year, day, input }) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+    }
+}
+
+/// Tests and solves the day, then again after every change, comparing the answers with the last run.
+fn watch_day(watch: &Watch) -> io::Result<()> {
+    let changes = watch.changes()?;
+    let mut previous = vec![];
+    loop {
+        println!("{} day {}", watch.year, watch.day);
+        let solve = match watch.test()? {
+            Tests::Passed(summary) => {
+                println!("tests  {}", summary);
+                true
+            }
+            Tests::Failed(failures) => {
+                println!("{}", failures);
+                true
+            }
+            Tests::Unbuildable(errors) => {
+                println!("{}", errors);
+                false
+            }
+        };
+        if solve {
+            match watch.solve()? {
+                Ok(answers) => {
+                    watch::diff(&previous, &answers).iter().for_each(|line| println!("{}", line));
+                    previous = answers;
+                }
+                Err(errors) => println!("{}", errors),
+            }
+        }
+        println!("watching {} and {}\n", watch.source().display(), watch.input.display());
+        let changed = changes.wait()?;
+        println!("{} changed", changed.display());
+    }
+}
+
+/// Logs warnings and, depending on `verbose`, debug or trace output of this crate to stderr.
+/// A `filter`, or $AOC_LOG, replaces the level set by `verbose`.
+fn init_logging(verbose: u8, filter: Option<String>) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "warn,aoc=debug,adventofcode=debug",
+        _ => "warn,aoc=trace,adventofcode=trace",
+    };
+    let filter = filter.or_else(|| std::env::var(LOG_VAR).ok()).unwrap_or_else(|| level.to_string());
+    let filter = EnvFilter::try_new(&filter).unwrap_or_else(|e| {
+        eprintln!("error: invalid log filter '{}': {}", filter, e);
+        exit(1);
+    });
+    tracing_subscriber::fmt().with_env_filter(filter).with_writer(|| LogWriter).with_ansi(io::stderr().is_terminal()).without_time().init();
+}
+
+/// Writes log output to the buffer of the puzzle being solved on this thread if it is captured,
+/// or else to stderr.
+struct LogWriter;
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        LOG.with(|log| match log.borrow_mut().as_mut() {
+            Some(log) => log.write(buf),
+            None => io::stderr().write(buf),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+/// All puzzles of `year`, or of all years if it is `None`, unless specific `days` are given.
+fn find_puzzles(year: Option<u16>, days: &[usize]) -> Vec<Puzzle> {
+    let Some(year) = year else {
+        return registry::puzzles();
+    };
+    if days.is_empty() {
+        let puzzles = registry::puzzles().into_iter().filter(|p| p.year == year).collect::<Vec<_>>();
+        if puzzles.is_empty() {
+            eprintln!("no solutions for {}, only for {}", year, registry::years().iter().join(", "));
+            exit(1);
+        }
+        return puzzles;
+    }
+    days.iter()
+        .map(|&day| registry::find(year, day).unwrap_or_else(|| {
+            eprintln!("no solution for {} day {}", year, day);
+            exit(1);
+        }))
+        .collect()
+}
+
+thread_local! {
+    /// Message of the last panic on this thread, set by the panic hook of `aoc run`.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Whether a puzzle is being solved on this thread, so that its panics are caught.
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+    /// Log output of the puzzle being solved on this thread, if it is captured.
+    static LOG: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Parts the puzzle is solved for, together with their failure if parsing fails or the solver panics,
+/// and the log output of solving it if it was captured.
+struct Attempt {
+    parts: Vec<Part>,
+    solved: Result<(String, Vec<Solved>), String>,
+    log: String,
+}
+
+/// Solves the puzzle, returning the hash of its input and the solved parts, or the error.
+/// Panics are caught, so this can run on a thread pool alongside other puzzles, whose log output
+/// does not mix with that of this puzzle if `capture_log` is set.
+fn solve(puzzle: &Puzzle, part: Option<Part>, input: Option<&Path>, capture_log: bool) -> Attempt {
+    // a solver using rayon may solve another puzzle on this thread while it waits, which must not
+    // take over the log and panic handling of this one
+    let outer_log = LOG.with(|log| log.replace(capture_log.then(Vec::new)));
+    let outer_solving = SOLVING.replace(true);
+    let _span = debug_span!("solve", year = puzzle.year, day = puzzle.day).entered();
+    let source = Source::for_day(puzzle.year, puzzle.day, input);
+    let parts = part.map(|p| vec![p]).unwrap_or_else(|| Part::all().to_vec());
+    let solved = panic::catch_unwind(|| source.read_text()
+        .and_then(|text| (puzzle.solve)(&text, &parts).map(|solved| (input_hash(&text), solved)))
+        .map_err(|e| e.in_file(&source).to_string()));
+    let solved = solved.unwrap_or_else(|_| Err(PANIC.with(|panic| panic.borrow_mut().take()).unwrap_or_else(|| String::from("panicked"))));
+    SOLVING.set(outer_solving);
+    let log = LOG.with(|log| log.replace(outer_log)).map(|log| String::from_utf8_lossy(&log).into_owned()).unwrap_or_default();
+    Attempt { parts, solved, log }
+}
+
+fn records(puzzle: &Puzzle, attempt: Attempt, answers: &mut Answers, record: bool) -> Vec<Record> {
+    let Attempt { parts, solved, .. } = attempt;
+    match solved {
+        Ok((hash, solved)) => solved.into_iter()
+            .map(|Solved { part, output, elapsed }| {
+                let (status, expected) = if record {
+                    answers.record(puzzle.year, puzzle.day, part, &hash, &output);
+                    ("RECORDED", None)
+                } else {
+                    match answers.check(puzzle.year, puzzle.day, part, &hash, &output) {
+                        Status::Pass => ("PASS", None),
+                        Status::Fail { expected } => ("FAIL", Some(expected)),
+                        Status::Unknown => ("UNKNOWN", None),
+                    }
+                };
+                Record {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part: part.to_string(),
+                    answer: Some(output.to_string()),
+                    elapsed_ms: Some(elapsed.as_secs_f64() * 1000.0),
+                    status,
+                    expected,
+                    error: None,
+                }
+            })
+            .collect(),
+        Err(e) => parts.iter()
+            .map(|part| Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: part.to_string(),
+                answer: None,
+                elapsed_ms: None,
+                status: "ERROR",
+                expected: None,
+                error: Some(e.clone()),
+            })
+            .collect(),
+    }
+}
+
+fn print_text(records: &[Record]) {
+    if let Some(error) = records.iter().find_map(|r| r.error.as_ref()) {
+        eprintln!("error: {} day {}: {}", records[0].year, records[0].day, error);
+        return;
+    }
+    for r in records {
+        let status = match &r.expected {
+            Some(expected) => format!("{} (expected {})", r.status, expected),
+            None => r.status.to_string(),
+        };
+        println!("{} day {} part {}: {} {} ({:.3} ms)", r.year, r.day, r.part, r.answer.as_deref().unwrap_or_default(), status, r.elapsed_ms.unwrap_or_default());
+    }
+}
+
+/// Prints one row per record, with multi-line answers after the table, and a summary of the statuses.
+fn print_table(records: &[Record], wall_time: Duration) {
+    let answer = |r: &Record| match &r.answer {
+        Some(answer) if answer.contains('\n') => String::from("(see below)"),
+        answer => answer.clone().unwrap_or_default(),
+    };
+    let width = records.iter().map(|r| answer(r).chars().count()).max().unwrap_or(0).max(6);
+    println!("{:>4} {:>3} {:<4} {:<width$} {:>12} status", "year", "day", "part", "answer", "time");
+    for r in records {
+        let time = r.elapsed_ms.map(|ms| duration(Duration::from_secs_f64(ms / 1000.0))).unwrap_or_default();
+        let note = match (&r.expected, &r.error) {
+            (Some(expected), _) => format!(" expected {}", expected),
+            (_, Some(error)) => format!(" {}", error),
+            _ => String::new(),
+        };
+        let row = format!("{:>4} {:>3} {:<4} {:<width$} {:>12} {:<8}{}", r.year, r.day, r.part, answer(r), time, r.status, note);
+        println!("{}", row.trim_end());
+    }
+    for r in records.iter().filter(|r| r.answer.as_ref().is_some_and(|a| a.contains('\n'))) {
+        println!("\n{} day {} part {}:\n{}", r.year, r.day, r.part, r.answer.as_deref().unwrap_or_default().trim_end());
+    }
+    let counts = records.iter().counts_by(|r| r.status);
+    let total = Duration::from_secs_f64(records.iter().filter_map(|r| r.elapsed_ms).sum::<f64>() / 1000.0);
+    println!("\n{} in {} ({} solving)",
+             counts.iter().sorted().map(|(status, count)| format!("{} {}", count, status)).join(", "),
+             duration(wall_time), duration(total));
+}
+
+fn print_csv(records: &[Record]) {
+    for r in records {
+        let fields = [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.clone(),
+            r.answer.clone().unwrap_or_default(),
+            r.elapsed_ms.map(|ms| format!("{:.3}", ms)).unwrap_or_default(),
+            r.status.to_string(),
+            r.expected.clone().unwrap_or_default(),
+            r.error.clone().unwrap_or_default(),
+        ];
+        println!("{}", fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(","));
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn run_bench(puzzle: &Puzzle, config: &Config, input: Option<&Path>) -> Vec<Measurement> {
+    let source = Source::for_day(puzzle.year, puzzle.day, input);
+    let timings = source.read_text()
+        .and_then(|text| (puzzle.bench)(&text, config))
+        .map_err(|e| e.in_file(&source));
+    match timings {
+        Ok(timings) => timings.into_iter()
+            .map(|(stage, timing)| Measurement::new(puzzle.year, puzzle.day, stage, timing))
+            .collect(),
+        Err(e) => {
+            eprintln!("error: {} day {}: {}", puzzle.year, puzzle.day, e);
+            vec![]
+        }
+    }
+}
+
+fn report_bench(measurements: &[Measurement], previous: Option<&[Measurement]>, threshold: f64) -> usize {
+    println!("{:>4} {:>3} {:<11} {:>12} {:>12} {:>12}", "year", "day", "stage", "min", "median", "max");
+    let mut regressions = 0;
+    for m in measurements {
+        let timing = m.timing();
+        print!("{:>4} {:>3} {:<11} {:>12} {:>12} {:>12}", m.year, m.day, m.stage, duration(timing.min), duration(timing.median), duration(timing.max));
+        if let Some(change) = previous.and_then(|previous| m.change_since(previous)) {
+            print!(" {:>+8.1}%", change * 100.0);
+            if change > threshold {
+                print!(" REGRESSION");
+                regressions += 1;
+            }
+        }
+        println!();
+    }
+    // parsing line by line is only measured for comparison, runs parse the whole text
+    let total = |f: fn(&Measurement) -> u64| duration(Duration::from_nanos(measurements.iter()
+        .filter(|m| m.stage != Stage::ParseLines)
+        .map(f)
+        .sum()));
+    println!("{:>4} {:>3} {:<11} {:>12} {:>12} {:>12}", "", "", "total", total(|m| m.min_ns), total(|m| m.median_ns), total(|m| m.max_ns));
+    regressions
+}
+
+fn duration(d: Duration) -> String {
+    format!("{:.2?}", d)
+}
@@ -0,0 +1,111 @@
+use std::iter;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::input::{lines, Line};
+
+/// The items of `text` between `separator`s, each parsed by `item`.
+pub fn list<'a, T>(text: &'a str, separator: &str, item: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    text.split(separator).map(item).collect()
+}
+
+/// A line of comma separated integers such as `3,4,3,1,2`.
+pub fn comma_list<T: TryFrom<i64>>(line: Line) -> Result<Vec<T>, ParseError> {
+    list(line.text, ",", |token| line.int(token))
+}
+
+/// The two sides of `text` around `separator`, e.g. of `CH -> B` or `patterns | digits`.
+pub fn split_pair<'a>(line: Line<'a>, text: &'a str, separator: &str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| line.missing(&format!("'{}' followed by {}", separator.trim(), expected)))
+}
+
+/// Comma separated integer coordinates such as `0,9` or `-618,-824,-621`, one per name.
+pub fn coords<T: TryFrom<i64>, const N: usize>(line: Line, text: &str, names: [&str; N]) -> Result<[T; N], ParseError> {
+    let mut tokens = text.split(',');
+    let mut coords = Vec::with_capacity(N);
+    for name in names {
+        coords.push(line.int(line.field(tokens.next(), name)?)?);
+    }
+    if let Some(extra) = tokens.next() {
+        return Err(line.invalid(extra, &format!("{} coordinates", N)));
+    }
+    Ok(coords.try_into().unwrap_or_else(|_| unreachable!("one coordinate per name")))
+}
+
+/// A segment between two points, `x1,y1 -> x2,y2`.
+pub fn segment<T: TryFrom<i64>>(line: Line) -> Result<([T; 2], [T; 2]), ParseError> {
+    let (from, to) = split_pair(line, line.text, " -> ", "an end point")?;
+    let names = ["an x coordinate", "a y coordinate"];
+    Ok((coords(line, from, names)?, coords(line, to, names)?))
+}
+
+/// An inclusive range of integers named `key`, e.g. `x=-20..26`.
+pub fn range<T: TryFrom<i64>>(line: Line, text: &str, key: &str) -> Result<(T, T), ParseError> {
+    let (from, to) = text.strip_prefix(key)
+        .and_then(|range| range.strip_prefix('='))
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| line.invalid(text, &format!("'{}=<from>..<to>'", key)))?;
+    Ok((line.int(from)?, line.int(to)?))
+}
+
+/// The number in a header line like `--- scanner 3 ---`, given its `prefix` and `suffix`.
+pub fn header<T: TryFrom<i64>>(line: Line, prefix: &str, suffix: &str) -> Result<T, ParseError> {
+    let number = line.text.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .ok_or_else(|| line.invalid(line.text, &format!("'{}<number>{}'", prefix, suffix)))?;
+    line.int(number)
+}
+
+/// The blocks of lines of `text` that are separated by one or more blank lines.
+pub fn blocks(text: &str) -> impl Iterator<Item=Vec<Line<'_>>> {
+    let mut lines = lines(text).peekable();
+    iter::from_fn(move || {
+        while lines.next_if(|line| line.text.is_empty()).is_some() {}
+        lines.peek()?;
+        Some(lines.by_ref().take_while(|line| !line.text.is_empty()).collect())
+    })
+}
+
+/// A grid with one cell per character, see [`Grid::parse`].
+pub fn grid<'a, T>(lines: impl IntoIterator<Item=Line<'a>>, expected: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    Grid::parse_numbered(lines.into_iter().map(|line| (line.number, line.text)), expected, cell)
+}
+
+/// A grid of decimal digits like the height map of 2021 day 9.
+pub fn digit_grid<'a>(lines: impl IntoIterator<Item=Line<'a>>) -> Result<Grid<usize>, ParseError> {
+    grid(lines, "a digit", |c| c.to_digit(10).map(|d| d as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{lines, Line};
+    use super::{blocks, comma_list, coords, digit_grid, header, range, segment};
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 1, text }
+    }
+
+    #[test]
+    fn test_line_shapes() {
+        assert_eq!(vec![3, 4, 3], comma_list::<u8>(line("3,4,3")).unwrap());
+        assert_eq!(([0, 9], [5, 9]), segment::<usize>(line("0,9 -> 5,9")).unwrap());
+        assert_eq!([-618, -824, -621], coords::<isize, 3>(line("-618,-824,-621"), "-618,-824,-621", ["x", "y", "z"]).unwrap());
+        assert_eq!((-20, 26), range::<isize>(line("x=-20..26"), "x=-20..26", "x").unwrap());
+        assert_eq!(3, header::<usize>(line("--- scanner 3 ---"), "--- scanner ", " ---").unwrap());
+
+        assert_eq!("<input>:1:4: missing '->' followed by an end point", segment::<usize>(line("0,9")).unwrap_err().to_string());
+        assert_eq!("<input>:1:5: expected 2 coordinates, found '1'", segment::<usize>(line("0,9,1 -> 5,9")).unwrap_err().to_string());
+        assert_eq!("<input>:1:1: expected 'y=<from>..<to>', found 'x=1..2'", range::<isize>(line("x=1..2"), "x=1..2", "y").unwrap_err().to_string());
+        assert_eq!("<input>:1:1: expected '--- scanner <number> ---', found 'scanner 3'", header::<usize>(line("scanner 3"), "--- scanner ", " ---").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_blocks_and_grids() {
+        let text = "\n12\n34\n\n\n5,6\n";
+        let blocks = blocks(text).collect::<Vec<_>>();
+        assert_eq!(2, blocks.len());
+        assert_eq!(vec![Line { number: 6, text: "5,6" }], blocks[1]);
+        assert_eq!("12\n34", digit_grid(blocks[0].iter().copied()).unwrap().to_string());
+        assert_eq!("<input>:2:2: missing 2 cells per row", digit_grid(lines("12\n3")).unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+use tracing::{debug, debug_span};
+use crate::*;
+use crate::bench::{self, Config, Stage, Timing};
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Part, Solution};
+
+pub type Validator = fn(&str) -> Result<(), ParseError>;
+pub type Solver = fn(&str, &[Part]) -> Result<Vec<Solved>, ParseError>;
+pub type Bencher = fn(&str, &Config) -> Result<Vec<(Stage, Timing)>, ParseError>;
+pub type Generator = fn(u64, Option<usize>) -> String;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: usize,
+    pub validate: Validator,
+    pub solve: Solver,
+    pub bench: Bencher,
+    pub generate: Generator,
+}
+
+pub struct Solved {
+    pub part: Part,
+    pub output: Output,
+    pub elapsed: Duration,
+}
+
+impl Puzzle {
+    fn new<S: Solution + Generate>(year: u16, day: usize) -> Self {
+        Puzzle { year, day, validate: validate::<S>, solve: solve::<S>, bench: bench::<S>, generate: generate::<S> }
+    }
+}
+
+fn validate<S: Solution>(text: &str) -> Result<(), ParseError> {
+    S::parse_text(text).map(|_| ())
+}
+
+fn solve<S: Solution>(text: &str, parts: &[Part]) -> Result<Vec<Solved>, ParseError> {
+    let input = S::parse_text(text)?;
+    Ok(parts.iter()
+        .map(|&part| {
+            let _span = debug_span!("part", %part).entered();
+            let start = Instant::now();
+            let output = match part {
+                Part::A => S::part_a(&input),
+                Part::B => S::part_b(&input),
+            };
+            let elapsed = start.elapsed();
+            debug!(?elapsed, "solved");
+            Solved { part, output, elapsed }
+        })
+        .collect())
+}
+
+fn bench<S: Solution>(text: &str, config: &Config) -> Result<Vec<(Stage, Timing)>, ParseError> {
+    let input = S::parse_text(text)?;
+    Ok(vec![
+        // the way inputs were parsed before parse_text, for comparison
+        (Stage::ParseLines, bench::measure(config, || S::parse(text.lines().map(str::to_string)))),
+        (Stage::Parse, bench::measure(config, || S::parse_text(text))),
+        (Stage::PartA, bench::measure(config, || S::part_a(&input))),
+        (Stage::PartB, bench::measure(config, || S::part_b(&input))),
+    ])
+}
+
+/// An input generated from `seed`, of the given size or the size of a real puzzle input.
+fn generate<S: Generate>(seed: u64, size: Option<usize>) -> String {
+    S::generate(&mut Rng::new(seed), size.unwrap_or(S::SIZE))
+}
+
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::new::<y2021::day_1::Day1>(2021, 1),
+        Puzzle::new::<y2021::day_2::Day2>(2021, 2),
+        Puzzle::new::<y2021::day_3::Day3>(2021, 3),
+        Puzzle::new::<y2021::day_4::Day4>(2021, 4),
+        Puzzle::new::<y2021::day_5::Day5>(2021, 5),
+        Puzzle::new::<y2021::day_6::Day6>(2021, 6),
+        Puzzle::new::<y2021::day_7::Day7>(2021, 7),
+        Puzzle::new::<y2021::day_8::Day8>(2021, 8),
+        Puzzle::new::<y2021::day_9::Day9>(2021, 9),
+        Puzzle::new::<y2021::day_10::Day10>(2021, 10),
+        Puzzle::new::<y2021::day_11::Day11>(2021, 11),
+        Puzzle::new::<y2021::day_12::Day12>(2021, 12),
+        Puzzle::new::<y2021::day_13::Day13>(2021, 13),
+        Puzzle::new::<y2021::day_14::Day14>(2021, 14),
+        Puzzle::new::<y2021::day_15::Day15>(2021, 15),
+        Puzzle::new::<y2021::day_16::Day16>(2021, 16),
+        Puzzle::new::<y2021::day_17::Day17>(2021, 17),
+        Puzzle::new::<y2021::day_18::Day18>(2021, 18),
+        Puzzle::new::<y2021::day_19::Day19>(2021, 19),
+        Puzzle::new::<y2021::day_20::Day20>(2021, 20),
+        Puzzle::new::<y2021::day_21::Day21>(2021, 21),
+        Puzzle::new::<y2021::day_22::Day22>(2021, 22),
+        Puzzle::new::<y2021::day_23::Day23>(2021, 23),
+        Puzzle::new::<y2021::day_24::Day24>(2021, 24),
+        Puzzle::new::<y2021::day_25::Day25>(2021, 25),
+    ]
+}
+
+pub fn years() -> Vec<u16> {
+    puzzles().into_iter().map(|p| p.year).dedup().collect()
+}
+
+pub fn find(year: u16, day: usize) -> Option<Puzzle> {
+    puzzles().into_iter().find(|p| p.year == year && p.day == day)
+}
@@ -0,0 +1,157 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use regex::Regex;
+use crate::store::{InputStore, Key};
+
+const TEMPLATE: &str = r#"use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        Ok(lines.collect())
+    }
+
+    fn part_a(_input: &Self::Input) -> Output {
+        Output::None
+    }
+
+    fn part_b(_input: &Self::Input) -> Output {
+        Output::None
+    }
+}
+
+impl Generate for Day__DAY__ {
+    /// Number of lines.
+    const SIZE: usize = 0;
+
+    fn generate(_rng: &mut Rng, _size: usize) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::{Output, Solution};
+    use super::Day__DAY__;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day__DAY__::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!(Output::None, Day__DAY__::part_a(&input));
+        assert_eq!(Output::None, Day__DAY__::part_b(&input));
+    }
+}
+"#;
+
+/// Creates the module of a new day from the template, registers it in `src/y<year>/mod.rs`
+/// (creating the year module if needed) and `src/registry.rs`, and puts an empty input into the
+/// store. Returns the created files.
+pub fn new_day(crate_root: &Path, store: &InputStore, year: u16, day: usize) -> io::Result<Vec<PathBuf>> {
+    let src = crate_root.join("src");
+    let year_dir = src.join(format!("y{}", year));
+    let module = year_dir.join(format!("day_{}.rs", day));
+    if module.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", module.display())));
+    }
+    let year_module = year_dir.join("mod.rs");
+    let registry = src.join("registry.rs");
+    let mut updates = vec![(registry.clone(), insert_line(&registry,
+        &format!("        Puzzle::new::<y{0}::day_{1}::Day{1}>({0}, {1}),", year, day),
+        r"Puzzle::new::<y(\d+)::day_(\d+)::", &[year as usize, day])?)];
+    let mut created = vec![];
+    if year_module.exists() {
+        updates.push((year_module.clone(), insert_line(&year_module, &format!("pub mod day_{};", day), r"^pub mod day_(\d+);", &[day])?));
+    } else {
+        let lib = src.join("lib.rs");
+        updates.push((lib.clone(), insert_line(&lib, &format!("pub mod y{};", year), r"^pub mod y(\d+);", &[year as usize])?));
+        updates.push((year_module.clone(), format!("pub mod day_{};\n", day)));
+        fs::create_dir_all(&year_dir)?;
+        created.push(year_module);
+    }
+
+    fs::write(&module, TEMPLATE.replace("__DAY__", &day.to_string()))?;
+    created.insert(0, module);
+    for (path, content) in updates {
+        fs::write(path, content)?;
+    }
+
+    let input = store.path(&Key::input(year, day));
+    if !input.exists() {
+        fs::create_dir_all(input.parent().expect("store paths have a parent"))?;
+        fs::write(&input, "")?;
+        created.push(input);
+    }
+    Ok(created)
+}
+
+/// Inserts `line` among the lines matching `pattern`, keeping them sorted by the numbers the
+/// pattern captures. Without a matching line, `line` is appended after the last `pub mod`.
+fn insert_line(path: &Path, line: &str, pattern: &str, key: &[usize]) -> io::Result<String> {
+    let pattern = Regex::new(pattern).expect("valid pattern");
+    let content = fs::read_to_string(path)?;
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let keys = lines.iter().enumerate()
+        .filter_map(|(i, l)| pattern.captures(l.trim_start()).map(|c| (i, c.iter().skip(1)
+            .map(|n| n.and_then(|n| n.as_str().parse().ok()).unwrap_or(0))
+            .collect::<Vec<usize>>())))
+        .collect::<Vec<_>>();
+    if keys.iter().any(|(_, k)| k == key) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("'{}' is already in {}", line.trim(), path.display())));
+    }
+    let at = match keys.iter().find(|(_, k)| k.as_slice() > key) {
+        Some((i, _)) => *i,
+        None => keys.last().map(|(i, _)| i + 1)
+            .or_else(|| lines.iter().rposition(|l| l.starts_with("pub mod ")).map(|i| i + 1))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("no place for '{}' in {}", line.trim(), path.display())))?,
+    };
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::store::InputStore;
+    use super::new_day;
+
+    #[test]
+    fn test_new_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("y2021")).unwrap();
+        fs::write(src.join("lib.rs"), "pub mod solution;\npub mod y2021;\n").unwrap();
+        fs::write(src.join("y2021/mod.rs"), "pub mod day_1;\npub mod day_3;\n").unwrap();
+        fs::write(src.join("registry.rs"), "    vec![\n        Puzzle::new::<y2021::day_1::Day1>(2021, 1),\n        Puzzle::new::<y2021::day_3::Day3>(2021, 3),\n    ]\n").unwrap();
+        let store = InputStore::new(dir.path().join("input"));
+
+        let created = new_day(dir.path(), &store, 2021, 2).unwrap();
+        assert_eq!(vec![src.join("y2021/day_2.rs"), dir.path().join("input/2021/day_2/input.txt")], created);
+        assert!(fs::read_to_string(src.join("y2021/day_2.rs")).unwrap().contains("impl Solution for Day2 {"));
+        assert_eq!("pub mod day_1;\npub mod day_2;\npub mod day_3;\n", fs::read_to_string(src.join("y2021/mod.rs")).unwrap());
+        assert!(new_day(dir.path(), &store, 2021, 2).is_err());
+
+        let created = new_day(dir.path(), &store, 2020, 1).unwrap();
+        assert_eq!(vec![src.join("y2020/day_1.rs"), src.join("y2020/mod.rs"), dir.path().join("input/2020/day_1/input.txt")], created);
+        assert_eq!("pub mod solution;\npub mod y2020;\npub mod y2021;\n", fs::read_to_string(src.join("lib.rs")).unwrap());
+        assert_eq!("pub mod day_1;\n", fs::read_to_string(src.join("y2020/mod.rs")).unwrap());
+        let registry = fs::read_to_string(src.join("registry.rs")).unwrap();
+        assert_eq!(vec![
+            "    vec![",
+            "        Puzzle::new::<y2020::day_1::Day1>(2020, 1),",
+            "        Puzzle::new::<y2021::day_1::Day1>(2021, 1),",
+            "        Puzzle::new::<y2021::day_2::Day2>(2021, 2),",
+            "        Puzzle::new::<y2021::day_3::Day3>(2021, 3),",
+            "    ]",
+        ], registry.lines().collect::<Vec<_>>());
+    }
+}
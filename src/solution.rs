@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::error::ParseError;
+use crate::input::join_lines;
+
+pub trait Solution {
+    type Input;
+
+    /// Parses the input line by line. Days implement either this or `parse_text`, which by default
+    /// call each other.
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        Self::parse_text(&join_lines(lines))
+    }
+
+    /// Parses the whole input at once. Days with large inputs override this to borrow lines and
+    /// fields from `text` instead of allocating a `String` for each line.
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(text.lines().map(str::to_string))
+    }
+
+    fn part_a(input: &Self::Input) -> Output;
+    fn part_b(input: &Self::Input) -> Output;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part { A, B }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    Number(i128),
+    Text(String),
+    None,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::A, Part::B]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self { Part::A => "a", Part::B => "b" })
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format!("unknown part '{}', expected a or b", s))
+        }
+    }
+}
+
+impl From<usize> for Output {
+    fn from(n: usize) -> Self {
+        Output::Number(n as i128)
+    }
+}
+
+impl From<u128> for Output {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map(Output::Number).unwrap_or_else(|_| Output::Text(n.to_string()))
+    }
+}
+
+impl From<isize> for Output {
+    fn from(n: isize) -> Self {
+        Output::Number(n as i128)
+    }
+}
+
+impl From<String> for Output {
+    fn from(s: String) -> Self {
+        Output::Text(s)
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Output::Number(n) => write!(f, "{}", n),
+            Output::Text(s) => write!(f, "{}", s),
+            Output::None => write!(f, "-"),
+        }
+    }
+}
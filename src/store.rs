@@ -0,0 +1,237 @@
+use std::{env, fs, vec};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use crate::error::ParseError;
+use crate::input::{read_lines, INPUT_DIR_VAR};
+
+pub const URL_VAR: &str = "AOC_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Identifies one file in the store, e.g. the puzzle input or the worked example of a day.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub year: u16,
+    pub day: usize,
+    pub variant: String,
+}
+
+/// Directory of puzzle inputs laid out as `<root>/<year>/day_<day>/<variant>.txt`.
+pub struct InputStore {
+    root: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    InvalidKey(Key),
+    Io { path: String, message: String },
+    Invalid(ParseError),
+    Conflict { path: String },
+    Fetch { url: String, message: String },
+}
+
+/// Source of puzzle inputs that are not in the store yet.
+pub trait Fetch {
+    fn fetch(&self, key: &Key) -> Result<String, StoreError>;
+}
+
+/// Fetches inputs over HTTP from adventofcode.com or any server with the same URL layout.
+pub struct HttpFetch {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Key {
+    pub fn new(year: u16, day: usize, variant: &str) -> Key {
+        Key { year, day, variant: variant.to_string() }
+    }
+
+    pub fn input(year: u16, day: usize) -> Key {
+        Key::new(year, day, "input")
+    }
+
+    pub fn example(year: u16, day: usize) -> Key {
+        Key::new(year, day, "example")
+    }
+
+    fn is_valid(&self) -> bool {
+        self.year >= 2015
+            && (1..=25).contains(&self.day)
+            && !self.variant.is_empty()
+            && self.variant.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/day_{}/{}", self.year, self.day, self.variant)
+    }
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputStore { root: root.into() }
+    }
+
+    /// The store in `$AOC_INPUT_DIR`, or in `input/` if it is not set.
+    pub fn from_env() -> Self {
+        InputStore::new(env::var_os(INPUT_DIR_VAR).map(PathBuf::from).unwrap_or_else(|| PathBuf::from("input")))
+    }
+
+    pub fn path(&self, key: &Key) -> PathBuf {
+        self.root.join(key.year.to_string()).join(format!("day_{}", key.day)).join(format!("{}.txt", key.variant))
+    }
+
+    pub fn contains(&self, key: &Key) -> bool {
+        self.path(key).is_file()
+    }
+
+    pub fn read_lines(&self, key: &Key) -> Result<vec::IntoIter<String>, ParseError> {
+        read_lines(self.path(key))
+    }
+
+    /// Validates `content` and files it under `key`. Replacing different content requires `force`.
+    pub fn import<V>(&self, key: &Key, content: &str, validate: V, force: bool) -> Result<PathBuf, StoreError>
+        where V: FnOnce(&str) -> Result<(), ParseError> {
+        if !key.is_valid() {
+            return Err(StoreError::InvalidKey(key.clone()));
+        }
+        let mut content = content.replace("\r\n", "\n");
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        validate(&content).map_err(StoreError::Invalid)?;
+
+        let path = self.path(key);
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == content => return Ok(path),
+            Ok(_) if !force => return Err(StoreError::Conflict { path: path.display().to_string() }),
+            _ => {}
+        }
+        let io_error = |e: std::io::Error| StoreError::Io { path: path.display().to_string(), message: e.to_string() };
+        fs::create_dir_all(path.parent().expect("store paths have a parent")).map_err(io_error)?;
+        fs::write(&path, content).map_err(io_error)?;
+        Ok(path)
+    }
+
+    /// Returns the cached file for `key`, fetching and importing it first if it is missing.
+    pub fn fetch<V>(&self, key: &Key, fetch: &dyn Fetch, validate: V, force: bool) -> Result<PathBuf, StoreError>
+        where V: FnOnce(&str) -> Result<(), ParseError> {
+        if self.contains(key) && !force {
+            return Ok(self.path(key));
+        }
+        let content = fetch.fetch(key)?;
+        self.import(key, &content, validate, force)
+    }
+}
+
+impl HttpFetch {
+    pub fn new(base_url: &str) -> Self {
+        HttpFetch { base_url: base_url.trim_end_matches('/').to_string(), session: None }
+    }
+
+    /// Uses `$AOC_URL` (default adventofcode.com) and the session cookie in `$AOC_SESSION`.
+    pub fn from_env() -> Self {
+        let mut fetch = HttpFetch::new(&env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string()));
+        fetch.session = env::var(SESSION_VAR).ok();
+        fetch
+    }
+
+    pub fn url(&self, key: &Key) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, key.year, key.day)
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, key: &Key) -> Result<String, StoreError> {
+        let url = self.url(key);
+        if key.variant != "input" {
+            return Err(StoreError::Fetch { url, message: format!("only puzzle inputs can be fetched, not '{}'", key.variant) });
+        }
+        let fetch_error = |message: String| StoreError::Fetch { url: url.clone(), message };
+        let mut request = ureq::get(&url);
+        if let Some(session) = &self.session {
+            request = request.header("Cookie", &format!("session={}", session));
+        }
+        request.call()
+            .map_err(|e| fetch_error(e.to_string()))?
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| fetch_error(e.to_string()))
+    }
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::InvalidKey(key) => write!(f, "invalid input key {}", key),
+            StoreError::Io { path, message } => write!(f, "{}: {}", path, message),
+            StoreError::Invalid(e) => write!(f, "invalid input: {}", e),
+            StoreError::Conflict { path } => write!(f, "{} already exists with different content", path),
+            StoreError::Fetch { url, message } => write!(f, "{}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use tiny_http::{Response, Server};
+    use crate::error::ParseError;
+    use super::{HttpFetch, InputStore, Key, StoreError};
+
+    fn numbers(text: &str) -> Result<(), ParseError> {
+        text.lines().enumerate()
+            .try_for_each(|(i, l)| l.parse::<usize>().map(|_| ()).map_err(|_| ParseError::invalid(i + 1, l, l, "usize")))
+    }
+
+    #[test]
+    fn test_import() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = InputStore::new(dir.path());
+        let key = Key::input(2021, 1);
+
+        let path = store.import(&key, "1\r\n2", numbers, false).unwrap();
+        assert_eq!(dir.path().join("2021/day_1/input.txt"), path);
+        assert_eq!(vec!["1", "2"], store.read_lines(&key).unwrap().collect::<Vec<_>>());
+
+        assert!(store.import(&key, "1\n2\n", numbers, false).is_ok());
+        assert!(matches!(store.import(&key, "3\n", numbers, false), Err(StoreError::Conflict { .. })));
+        assert!(matches!(store.import(&key, "x\n", numbers, true), Err(StoreError::Invalid(_))));
+        assert!(matches!(store.import(&Key::new(2021, 26, "input"), "1\n", numbers, false), Err(StoreError::InvalidKey(_))));
+        assert!(matches!(store.import(&Key::new(1999, 1, "input"), "1\n", numbers, false), Err(StoreError::InvalidKey(_))));
+        assert!(matches!(store.import(&Key::new(2021, 1, "../x"), "1\n", numbers, false), Err(StoreError::InvalidKey(_))));
+        store.import(&key, "3\n", numbers, true).unwrap();
+        assert_eq!(vec!["3"], store.read_lines(&key).unwrap().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_fetch_from_local_server() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let url = request.url().to_string();
+            let cookie = request.headers().iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            request.respond(Response::from_string("7\n8\n")).unwrap();
+            (url, cookie)
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = InputStore::new(dir.path());
+        let mut fetch = HttpFetch::new(&base_url);
+        fetch.session = Some("abc".to_string());
+
+        let path = store.fetch(&Key::input(2021, 5), &fetch, numbers, false).unwrap();
+        assert_eq!((String::from("/2021/day/5/input"), Some(String::from("session=abc"))), handle.join().unwrap());
+        assert_eq!("7\n8\n", std::fs::read_to_string(&path).unwrap());
+
+        // the server is gone, so this must be served from the store
+        assert_eq!(path, store.fetch(&Key::input(2021, 5), &fetch, numbers, false).unwrap());
+        assert!(matches!(store.fetch(&Key::input(2021, 6), &fetch, numbers, false), Err(StoreError::Fetch { .. })));
+    }
+}
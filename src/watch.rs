@@ -0,0 +1,196 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+/// Tests and solves one day with cargo, so that changes to its source are built first.
+pub struct Watch {
+    pub root: PathBuf,
+    pub year: u16,
+    pub day: usize,
+    pub input: PathBuf,
+}
+
+/// Filesystem notifications for the watched files.
+pub struct Changes {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    files: Vec<PathBuf>,
+}
+
+pub enum Tests {
+    Passed(String),
+    Failed(String),
+    Unbuildable(String),
+}
+
+/// One record of `aoc run --format json`.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Answer {
+    pub part: String,
+    pub answer: Option<String>,
+    pub elapsed_ms: Option<f64>,
+    pub status: String,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Watch {
+    pub fn source(&self) -> PathBuf {
+        self.root.join("src").join(format!("y{}", self.year)).join(format!("day_{}.rs", self.day))
+    }
+
+    /// Starts watching the source and input of the day. Their directories are watched rather than
+    /// the files themselves, which editors often replace instead of writing to them.
+    pub fn changes(&self) -> io::Result<Changes> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+        let mut files = vec![];
+        for path in [self.source(), self.input.clone()] {
+            let dir = fs::canonicalize(path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")))
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+            watcher.watch(&dir, RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+            files.push(dir.join(path.file_name().unwrap_or_default()));
+        }
+        Ok(Changes { _watcher: watcher, events, files })
+    }
+
+    /// Runs the tests of the day's module.
+    pub fn test(&self) -> io::Result<Tests> {
+        let filter = format!("y{}::day_{}::", self.year, self.day);
+        let output = self.cargo(&["test", "--release", "--quiet", "--lib", "--", &filter])?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let Some(result) = stdout.lines().find_map(|l| l.strip_prefix("test result: ")) else {
+            return Ok(Tests::Unbuildable(errors(&String::from_utf8_lossy(&output.stderr))));
+        };
+        if output.status.success() {
+            return Ok(Tests::Passed(result.split("; ").take(3).collect::<Vec<_>>().join(", ")));
+        }
+        let failures = stdout.find("failures:").map(|start| &stdout[start..]).unwrap_or(&stdout);
+        let failures = failures.find("test result:").map(|end| &failures[..end]).unwrap_or(failures);
+        Ok(Tests::Failed(failures.trim().to_string()))
+    }
+
+    /// Solves both parts with `aoc run`, or returns why it could not be built or run.
+    pub fn solve(&self) -> io::Result<Result<Vec<Answer>, String>> {
+        let (year, day) = (self.year.to_string(), self.day.to_string());
+        let input = self.input.to_string_lossy();
+        let output = self.cargo(&["run", "--release", "--quiet", "--", "run", &year, &day, "--input", &input, "--format", "json"])?;
+        Ok(serde_json::from_slice(&output.stdout).map_err(|_| errors(&String::from_utf8_lossy(&output.stderr))))
+    }
+
+    fn cargo(&self, args: &[&str]) -> io::Result<std::process::Output> {
+        Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+            .args(args)
+            .current_dir(&self.root)
+            .output()
+    }
+}
+
+impl Changes {
+    /// Blocks until a watched file changes and returns it, once it has been quiet for a moment.
+    pub fn wait(&self) -> io::Result<PathBuf> {
+        loop {
+            let event = self.events.recv().map_err(io::Error::other)?.map_err(io::Error::other)?;
+            if let Some(file) = changed_file(&event, &self.files) {
+                // editors and `aoc import` may write a file in several steps
+                while self.events.recv_timeout(Duration::from_millis(200)).is_ok() {}
+                return Ok(file);
+            }
+        }
+    }
+}
+
+fn changed_file(event: &Event, files: &[PathBuf]) -> Option<PathBuf> {
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+        return None;
+    }
+    event.paths.iter().find(|path| files.contains(path)).cloned()
+}
+
+/// The compiler errors in the output of cargo, or all of it if there are none.
+fn errors(stderr: &str) -> String {
+    stderr.find("error").map(|start| &stderr[start..]).unwrap_or(stderr).trim().to_string()
+}
+
+/// One line per part with its answer, status and time, noting how they changed since `previous`.
+/// Answers of several lines are shown in full below, if they changed.
+pub fn diff(previous: &[Answer], current: &[Answer]) -> Vec<String> {
+    let text = |a: &Answer| match (&a.answer, &a.error) {
+        (Some(answer), _) if answer.contains('\n') => format!("({} lines)", answer.trim_end().lines().count()),
+        (Some(answer), _) => answer.clone(),
+        (None, error) => error.clone().unwrap_or_default(),
+    };
+    let width = current.iter().map(|a| text(a).chars().count()).max().unwrap_or(0);
+    let mut lines = vec![];
+    let mut below = vec![];
+    for a in current {
+        let before = previous.iter().find(|p| p.part == a.part);
+        let mut line = format!("part {} {:<width$} {:<8}", a.part, text(a), a.status);
+        if let Some(ms) = a.elapsed_ms {
+            line += &format!(" {:>10.3} ms", ms);
+            if let Some(before_ms) = before.and_then(|b| b.elapsed_ms) {
+                line += &format!(" {:>+10.3} ms", ms - before_ms);
+            }
+        }
+        if let Some(expected) = &a.expected {
+            line += &format!(" expected {}", expected);
+        }
+        match before {
+            Some(before) if before.answer != a.answer => {
+                line += &format!(" (was {})", before.answer.as_ref().map(|_| text(before)).unwrap_or_else(|| before.status.clone()));
+                below.extend(a.answer.iter().filter(|answer| answer.contains('\n')).map(|answer| answer.trim_end().to_string()));
+            }
+            None => below.extend(a.answer.iter().filter(|answer| answer.contains('\n')).map(|answer| answer.trim_end().to_string())),
+            _ => {}
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.extend(below);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use notify::{Event, EventKind};
+    use notify::event::{AccessKind, ModifyKind};
+    use super::{changed_file, diff, Answer};
+
+    fn answer(part: &str, answer: &str, elapsed_ms: f64) -> Answer {
+        Answer { part: part.to_string(), answer: Some(answer.to_string()), elapsed_ms: Some(elapsed_ms), status: String::from("UNKNOWN"), expected: None, error: None }
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = vec![answer("a", "1588", 1.5), answer("b", "2188189693529", 20.0)];
+        assert_eq!(vec![
+            "part a 1588          UNKNOWN       1.500 ms",
+            "part b 2188189693529 UNKNOWN      20.000 ms",
+        ], diff(&[], &first));
+
+        let second = vec![answer("a", "1588", 1.25), answer("b", "2188189693530", 30.0)];
+        assert_eq!(vec![
+            "part a 1588          UNKNOWN       1.250 ms     -0.250 ms",
+            "part b 2188189693530 UNKNOWN      30.000 ms    +10.000 ms (was 2188189693529)",
+        ], diff(&first, &second));
+
+        let failed = Answer { answer: None, elapsed_ms: None, status: String::from("ERROR"), error: Some(String::from("panicked")), ..answer("a", "", 0.0) };
+        assert_eq!(vec!["part a panicked ERROR    (was 1588)"], diff(&second[..1], &[failed]));
+        assert_eq!(vec!["part b (2 lines) UNKNOWN       1.000 ms", "#.\n.#"], diff(&[], &[answer("b", "#.\n.#\n", 1.0)]));
+    }
+
+    #[test]
+    fn test_changed_file() {
+        let files = vec![PathBuf::from("/crate/src/y2021/day_1.rs"), PathBuf::from("/input/2021/day_1/input.txt")];
+        let modify = |path: &str| Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path));
+        assert_eq!(Some(files[0].clone()), changed_file(&modify("/crate/src/y2021/day_1.rs"), &files));
+        assert_eq!(None, changed_file(&modify("/crate/src/y2021/day_10.rs"), &files));
+        assert_eq!(None, changed_file(&Event::new(EventKind::Access(AccessKind::Any)).add_path(files[1].clone()), &files));
+    }
+}
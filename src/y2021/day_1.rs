@@ -0,0 +1,158 @@
+use std::ops::Range;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Output, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = SonarSweep;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        lines(text)
+            .map(|line| line.int(line.text))
+            .collect::<Result<_, _>>()
+            .map(SonarSweep::new)
+    }
+
+    fn part_a(sweep: &Self::Input) -> Output {
+        sweep.increases(1).into()
+    }
+
+    fn part_b(sweep: &Self::Input) -> Output {
+        sweep.increases(3).into()
+    }
+}
+
+impl Generate for Day1 {
+    /// Number of depth measurements.
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // the sea floor mostly gets deeper, but not always
+        let mut depth = rng.range(100..=200);
+        (0..size.max(1))
+            .map(|_| {
+                depth = (depth + rng.range(-10..=20)).max(0);
+                format!("{}\n", depth)
+            })
+            .collect()
+    }
+}
+
+/// The depth measurements of a sonar sweep, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SonarSweep {
+    depths: Vec<usize>,
+}
+
+/// A decrease from one depth to the next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepthDrop {
+    /// Index of the depth it drops to.
+    pub index: usize,
+    pub drop: usize,
+}
+
+impl SonarSweep {
+    pub fn new(depths: Vec<usize>) -> Self {
+        SonarSweep { depths }
+    }
+
+    pub fn depths(&self) -> &[usize] {
+        &self.depths
+    }
+
+    /// How often the sum of a window of `size` depths is larger than that of the window before.
+    /// Consecutive windows share all but the first depth of one and the last of the other, so only
+    /// those two are compared.
+    pub fn increases(&self, size: usize) -> usize {
+        if size == 0 {
+            return 0;
+        }
+        self.depths.iter()
+            .zip(self.depths.iter().skip(size))
+            .filter(|(first, last)| last > first)
+            .count()
+    }
+
+    /// The sums of all windows of `size` depths, in order, e.g. to plot a smoothed sweep.
+    pub fn window_sums(&self, size: usize) -> Vec<usize> {
+        if size == 0 || size > self.depths.len() {
+            return vec![];
+        }
+        let mut sum = self.depths[..size].iter().sum::<usize>();
+        let mut sums = vec![sum];
+        for (first, last) in self.depths.iter().zip(&self.depths[size..]) {
+            sum = sum + last - first;
+            sums.push(sum);
+        }
+        sums
+    }
+
+    /// The indices of the first longest run of depths that increase from one to the next.
+    pub fn longest_increasing_run(&self) -> Range<usize> {
+        let mut longest = 0..self.depths.len().min(1);
+        let mut start = 0;
+        for i in 1..self.depths.len() {
+            if self.depths[i] <= self.depths[i - 1] {
+                start = i;
+            } else if i + 1 - start > longest.len() {
+                longest = start..i + 1;
+            }
+        }
+        longest
+    }
+
+    /// The largest decrease from one depth to the next, the first one if several are as large.
+    pub fn largest_drop(&self) -> Option<DepthDrop> {
+        (1..self.depths.len())
+            .filter(|&i| self.depths[i] < self.depths[i - 1])
+            .map(|index| DepthDrop { index, drop: self.depths[index - 1] - self.depths[index] })
+            .max_by_key(|drop| (drop.drop, usize::MAX - drop.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{Day1, DepthDrop, SonarSweep};
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn test_example() {
+        let input = Day1::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("7", Day1::part_a(&input).to_string());
+        assert_eq!("5", Day1::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_sonar_sweep() {
+        let sweep = Day1::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!(vec![7, 5, 5, 6, 5, 4], (1..=6).map(|size| sweep.increases(size)).collect::<Vec<_>>());
+        assert_eq!(0, sweep.increases(10));
+        assert_eq!(vec![607, 618, 618, 617, 647, 716, 769, 792], sweep.window_sums(3));
+        assert_eq!(sweep.depths().to_vec(), sweep.window_sums(1));
+        assert!(sweep.window_sums(11).is_empty());
+        assert_eq!(0..4, sweep.longest_increasing_run());
+        assert_eq!(Some(DepthDrop { index: 4, drop: 10 }), sweep.largest_drop());
+
+        let flat = SonarSweep::new(vec![3, 3]);
+        assert_eq!(0..1, flat.longest_increasing_run());
+        assert_eq!(None, flat.largest_drop());
+        assert_eq!(0..0, SonarSweep::new(vec![]).longest_increasing_run());
+    }
+}
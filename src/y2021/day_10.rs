@@ -0,0 +1,133 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        (1..).zip(lines)
+            .map(|(n, line)| match line.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+                Some((i, c)) => Err(ParseError::invalid(n, &line, &line[i..i + c.len_utf8()], "a bracket")),
+                None => Ok(line),
+            })
+            .collect()
+    }
+
+    fn part_a(lines: &Self::Input) -> Output {
+        lines.iter().map(|line| corruption_score(line)).sum::<usize>().into()
+    }
+
+    fn part_b(lines: &Self::Input) -> Output {
+        let scores = lines.iter().filter(|line| corruption_score(line) == 0)
+            .map(|line| completion_score(line))
+            .sorted()
+            .collect_vec();
+        scores[scores.len()/2].into()
+    }
+}
+
+impl Generate for Day10 {
+    /// Number of lines. Each is corrupted or incomplete, and an odd number of them is incomplete
+    /// so that there is a middle completion score.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut incomplete = (0..size).map(|i| i < (size / 2) | 1).collect_vec();
+        rng.shuffle(&mut incomplete);
+        incomplete.into_iter().map(|incomplete| random_chunks(rng, incomplete) + "\n").collect()
+    }
+}
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// A line of chunks that ends with open chunks if it is `incomplete`, or else continues with an
+/// illegal closing bracket and some more brackets.
+fn random_chunks(rng: &mut Rng, incomplete: bool) -> String {
+    let length = rng.range(10..=100) as usize;
+    let (mut line, mut open) = (String::new(), vec![]);
+    // few enough chunks are open at a time for completion scores to fit into a usize
+    while line.len() < length || open.is_empty() {
+        if open.is_empty() || open.len() < 12 && rng.chance(0.55) {
+            let (opening, closing) = *rng.choose(&BRACKETS);
+            line.push(opening);
+            open.push(closing);
+        } else {
+            line.push(open.pop().expect("an open chunk"));
+        }
+    }
+    if !incomplete {
+        let expected = open[open.len() - 1];
+        let illegal = BRACKETS.iter().map(|b| b.1).filter(|&c| c != expected).collect_vec();
+        line.push(*rng.choose(&illegal));
+        for _ in 0..rng.range(0..=20) {
+            let (opening, closing) = *rng.choose(&BRACKETS);
+            line.push(if rng.chance(0.5) { opening } else { closing });
+        }
+    }
+    line
+}
+
+fn completion_score(line: &str) -> usize {
+    let mut stack = vec![];
+
+    line.chars().for_each(|c| match c {
+        ')' | ']' | '}' | '>' => {stack.pop();},
+        s => stack.push(s),
+    });
+
+    stack.iter().rev().map(|c| match c {
+        '('  => 1, '[' => 2, '{' => 3, '<' => 4,
+        _ => panic!("unexpected char {}", c)
+    } as usize).fold(0, |sum , score| sum * 5 + score )
+}
+
+fn corruption_score(line: &str) -> usize {
+    let mut stack = vec![];
+    let mut total_score = 0;
+
+    for c in line.chars() {
+        let score = match c {
+            ')' => stack.pop().filter(|c| c==&'(').map_or(3, |_|0),
+            ']' => stack.pop().filter(|c| c==&'[').map_or(57, |_|0),
+            '}' => stack.pop().filter(|c| c==&'{').map_or(1197, |_|0),
+            '>' => stack.pop().filter(|c| c==&'<').map_or(25137, |_|0),
+            s =>  {stack.push(s); 0},
+        };
+        if score > 0 {
+            total_score += score;
+            break;
+        }
+    };
+    total_score
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day10;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn test_example() {
+        let input = Day10::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("26397", Day10::part_a(&input).to_string());
+        assert_eq!("288957", Day10::part_b(&input).to_string());
+    }
+}
@@ -0,0 +1,108 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::{Grid, Position};
+use crate::input::lines;
+use crate::parse::digit_grid;
+use crate::solution::{Output, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<usize>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        digit_grid(lines(text))
+    }
+
+    fn part_a(grid: &Self::Input) -> Output {
+        let mut grid = grid.clone();
+        let mut count = 0;
+        for _ in 0..100 {
+           count += run_step(&mut grid);
+        }
+        count.into()
+    }
+
+    fn part_b(grid: &Self::Input) -> Output {
+        let mut grid = grid.clone();
+        let field_count = grid.len();
+        let mut flash_count = 0;
+        let mut step: usize = 0;
+        while flash_count != field_count {
+            flash_count = run_step(&mut grid);
+            step += 1;
+        }
+        step.into()
+    }
+}
+
+impl Generate for Day11 {
+    /// Side of the square grid of octopuses, at most 30. Grids are drawn until their octopuses all
+    /// flash at once within 1000 steps, which larger grids hardly ever do.
+    const SIZE: usize = 10;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.clamp(1, 30);
+        loop {
+            let grid = Grid::from_fn(size, size, |_| rng.below(10));
+            let mut octopuses = grid.clone();
+            if (0..1000).any(|_| run_step(&mut octopuses) == octopuses.len()) {
+                return grid.to_string() + "\n";
+            }
+        }
+    }
+}
+
+fn run_step(grid: &mut Grid<usize>) -> usize {
+    let mut flashing = rise_energy_of(grid, grid.positions().collect_vec());
+    let mut flash_count = flashing.len();
+    while !flashing.is_empty() {
+        let adjacent = flashing.iter()
+            .flat_map(|&p| grid.neighbours_8(p))
+            .collect_vec();
+        flashing = rise_energy_of(grid, adjacent);
+        flash_count += flashing.len();
+    }
+    reset_flashed_ones(grid);
+    flash_count
+}
+
+fn rise_energy_of(grid: &mut Grid<usize>, positions: Vec<Position>) -> Vec<Position> {
+    positions.into_iter().filter(|&p| {
+        grid[p] += 1;
+        grid[p] == 10
+    }).collect()
+}
+
+fn reset_flashed_ones(grid: &mut Grid<usize>) {
+    grid.values_mut()
+        .filter(|energy| **energy > 9)
+        .for_each(|energy| *energy = 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day11;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn test_example() {
+        let input = Day11::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("1656", Day11::part_a(&input).to_string());
+        assert_eq!("195", Day11::part_b(&input).to_string());
+    }
+}
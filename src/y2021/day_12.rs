@@ -0,0 +1,206 @@
+use std::collections::{HashMap};
+use itertools::Itertools;
+use crate::error::{expect_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Arena;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        read_input(lines)
+    }
+
+    fn part_a(arena: &Self::Input) -> Output {
+        let paths = tail(arena, vec![arena.index_of("start") ], false);
+        paths.len().into()
+    }
+
+    fn part_b(arena: &Self::Input) -> Output {
+        let paths = tail(arena, vec![arena.index_of("start") ], true);
+        paths.len().into()
+    }
+}
+
+impl Generate for Day12 {
+    /// Number of small caves, with a big cave for every four of them. Big caves are never
+    /// connected to each other, which would allow infinitely many paths, but the number of paths
+    /// still grows quickly with the size.
+    const SIZE: usize = 10;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let small = cave_names(rng, size.max(1), 'a');
+        let big = cave_names(rng, (size / 4).max(1), 'A');
+        let mut paths = vec![];
+        for cave in &small {
+            paths.push((cave, rng.choose(&big)));
+            let other = rng.choose(&small);
+            if other != cave && rng.chance(0.5) {
+                paths.push((cave, other));
+            }
+        }
+        let caves = small.iter().chain(&big).cloned().collect_vec();
+        let ends = [String::from("start"), String::from("end")];
+        for end in &ends {
+            paths.push((end, rng.choose(&caves)));
+            paths.push((end, rng.choose(&caves)));
+        }
+        let mut paths = paths.into_iter().map(|(a, b)| (a.min(b), a.max(b))).sorted().dedup().collect_vec();
+        rng.shuffle(&mut paths);
+        paths.into_iter()
+            .map(|(a, b)| if rng.chance(0.5) { format!("{}-{}\n", a, b) } else { format!("{}-{}\n", b, a) })
+            .collect()
+    }
+}
+
+/// `count` distinct cave names of two letters, e.g. lowercase ones if `first` is `'a'`.
+fn cave_names(rng: &mut Rng, count: usize, first: char) -> Vec<String> {
+    let letters = (first..).take(26).collect_vec();
+    let mut names = letters.iter().cartesian_product(&letters)
+        .map(|(a, b)| format!("{}{}", a, b))
+        .collect_vec();
+    rng.shuffle(&mut names);
+    names.truncate(count);
+    names
+}
+
+fn tail(arena: &Arena, head: Vec<usize>, free_double_visit: bool) -> Vec<Vec<usize>> {
+    let last = head.last().expect("no head");
+    let node = arena.get(*last);
+    let end = arena.index_of("end");
+    if node.peers.is_empty() {
+        vec![head]
+    } else {
+        node.peers.iter()
+            .filter_map(|p| {
+                let is_double_visit = arena.get(*p).is_small && head.contains(p);
+                if free_double_visit && is_double_visit {
+                    Some((p, false))
+                }else if is_double_visit {
+                    None
+                } else {
+                    Some((p, free_double_visit))
+                }
+            })
+            .flat_map(|(p, free_double_visit)| {
+                let mut h = head.clone();
+                h.push(*p);
+                tail(arena, h, free_double_visit)
+            })
+            .filter(|p| *p.last().expect("empty path") == end)
+            .collect_vec()
+    }
+}
+
+
+
+fn read_input(lines: impl Iterator<Item=String>) -> Result<Arena, ParseError> {
+    let mut arena = Arena::new();
+    for (n, line) in (1..).zip(lines) {
+        let mut caves = line.split('-');
+        let from = expect_token(n, &line, caves.next(), "a cave")?;
+        let to = expect_token(n, &line, caves.next(), "'-' followed by a cave")?;
+        if let Some(extra) = caves.next() {
+            return Err(ParseError::invalid(n, &line, extra, "end of line"));
+        }
+        arena.add_path(from, to);
+    }
+    Ok(arena)
+}
+
+#[derive(Debug)]
+struct Node {peers: Vec<usize>, is_small: bool}
+impl Node {
+    fn new(is_small: bool) -> Node {
+        Node {peers: vec![], is_small}
+    }
+}
+
+pub struct Arena {
+    node: Vec<Node>,
+    node_to_index: HashMap<String, usize>,
+}
+impl Arena {
+    fn new() -> Arena {
+        Arena {node: vec![], node_to_index: HashMap::new()}
+    }
+    fn add_path(&mut self, from: &str, to: &str) {
+        let from_index = self.add(from);
+        let to_index = self.add(to);
+
+
+        match (from, to) {
+            ("start", _ ) | (_, "end" )  => {
+                self.node[from_index].peers.push(to_index);
+            },
+            (_, "start" ) | ("end", _ ) => {
+                self.node[to_index].peers.push(from_index);
+            },
+            _ => {
+                self.node[to_index].peers.push(from_index);
+                self.node[from_index].peers.push(to_index);
+            }
+        }
+    }
+    fn add(&mut self, node: &str) -> usize{
+        if let Some(index) = self.node_to_index.get(node) {
+            *index
+        } else {
+            let is_small = node != "start" && node != "end" && node.to_lowercase() == node;
+            self.node.push(Node::new(is_small));
+            let index = self.node.len()-1;
+            self.node_to_index.insert(node.to_string(), index);
+            index
+        }
+    }
+    fn get(&self, index: usize) -> &Node {
+        self.node.get(index).expect("index not found")
+    }
+    fn index_of(&self, node: &str) -> usize {
+        *self.node_to_index.get(node).expect("node not found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day12;
+
+    const SMALL_EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const LARGER_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    #[test]
+    fn test_small_example() {
+        let input = Day12::parse(example_lines(SMALL_EXAMPLE)).unwrap();
+        assert_eq!("10", Day12::part_a(&input).to_string());
+        assert_eq!("36", Day12::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let input = Day12::parse(example_lines(LARGER_EXAMPLE)).unwrap();
+        assert_eq!("19", Day12::part_a(&input).to_string());
+        assert_eq!("103", Day12::part_b(&input).to_string());
+    }
+}
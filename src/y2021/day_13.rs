@@ -0,0 +1,228 @@
+use std::fmt::{Debug, Formatter};
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::Grid;
+use crate::input::Line;
+use crate::parse::{blocks, coords, split_pair};
+use crate::solution::{Output, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<Point>, Vec<Fold>);
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        read_input(text)
+    }
+
+    fn part_a((points, folds): &Self::Input) -> Output {
+        let mut grid = Sheet::new(points, folds);
+        grid.fold(&folds[..1]);
+        grid.count_marked().into()
+    }
+
+    fn part_b((points, folds): &Self::Input) -> Output {
+        let mut grid = Sheet::new(points, folds);
+        grid.fold(folds);
+        format!("{:?}", &grid).into()
+    }
+}
+
+impl Generate for Day13 {
+    /// Number of dots. They are folded twelve times along the middle of the paper, onto a sheet
+    /// of 40 by 6 like that of eight capital letters.
+    const SIZE: usize = 800;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut axes = [['x'; 5].as_slice(), &['y'; 7]].concat();
+        rng.shuffle(&mut axes);
+        let (mut width, mut height) = (40, 6);
+        let mut unfolds = vec![];
+        for axis in axes {
+            let line = if axis == 'x' { &mut width } else { &mut height };
+            unfolds.push((axis, *line));
+            *line = 2 * *line + 1;
+        }
+        // dots on the folded sheet, each unfolded to a random side of every fold line
+        let dots = (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.below(40), rng.below(6));
+                for &(axis, line) in &unfolds {
+                    if rng.chance(0.5) {
+                        let coordinate = if axis == 'x' { &mut x } else { &mut y };
+                        *coordinate = 2 * line - *coordinate;
+                    }
+                }
+                format!("{},{}\n", x, y)
+            })
+            .unique()
+            .collect::<String>();
+        let folds = unfolds.iter().rev().map(|(axis, line)| format!("fold along {}={}\n", axis, line)).collect::<String>();
+        dots + "\n" + &folds
+    }
+}
+
+impl Sheet {
+    fn new(points: &[Point], folds: &[Fold]) -> Sheet {
+        let (width, height) = sheet_size(points, folds);
+        let mut fields = Grid::new(width, height, false);
+        points.iter().for_each(|&p| fields[p] = true);
+        Sheet(fields)
+    }
+
+    fn fold_up(&mut self, at_row: usize) {
+        let fields = &self.0;
+        let height = fields.height();
+        self.0 = Grid::from_fn(fields.width(), at_row, |(x, y)| {
+            fields[(x, y)] || fields.get((x, height - y - 1)).is_some_and(|v| *v)
+        });
+    }
+
+    fn fold_left(&mut self, at_col: usize) {
+        let fields = &self.0;
+        let width = fields.width();
+        self.0 = Grid::from_fn(at_col, fields.height(), |(x, y)| {
+            fields[(x, y)] || fields.get((width - x - 1, y)).is_some_and(|v| *v)
+        });
+    }
+
+    fn fold(&mut self, folds: &[(String, usize)]) {
+        for (dir, pos) in folds.iter() {
+            match (dir.as_str(), pos) {
+                ("x", pos) => self.fold_left(*pos),
+                ("y", pos) => self.fold_up(*pos),
+                _ => panic!("unexpected fold")
+            }
+        }
+    }
+
+    fn count_marked(&self) -> usize {
+        self.0.values().filter(|v| **v).count()
+    }
+}
+
+impl Debug for Sheet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut s = "    ".to_string();
+        for x in 0..self.0.width() {
+            s.push_str(&format!("{}", x%10));
+        }
+        s.push('\n');
+        for (y, row) in self.0.rows().enumerate() {
+            s.push_str(&format!("{: <3}|", y));
+            for field in row {
+                s.push_str(if *field { "█" } else { "." });
+            }
+            s.push('\n');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+type Point = (usize, usize);
+type Fold = (String, usize);
+
+/// The width and height of the sheet, twice the largest fold line plus one along each axis, or
+/// as far as the dots go along an axis that is not folded.
+fn sheet_size(points: &[Point], folds: &[Fold]) -> (usize, usize) {
+    let size = |axis: &str, coordinate: fn(&Point) -> usize| folds.iter()
+        .filter(|(a, _)| a == axis)
+        .map(|(_, index)| 2 * index + 1)
+        .max()
+        .unwrap_or_else(|| points.iter().map(|p| coordinate(p) + 1).max().unwrap_or(0));
+    (size("x", |p| p.0), size("y", |p| p.1))
+}
+
+fn read_input(text: &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let mut blocks = blocks(text);
+    let dot_lines = blocks.next().unwrap_or_default();
+    let points = dot_lines.iter()
+        .map(|&line| coords(line, line.text, ["an x coordinate", "a y coordinate"]).map(|[x, y]| (x, y)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let fold_lines = blocks.next().unwrap_or_default();
+    let folds = fold_lines.iter()
+        .map(|&line| parse_fold(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(line) = blocks.next().and_then(|block| block.first().copied()) {
+        return Err(line.invalid(line.text, "the end of the fold instructions"));
+    }
+    if folds.is_empty() {
+        return Err(ParseError::missing(text.lines().count() + 1, "", "fold instructions"));
+    }
+    let (mut width, mut height) = sheet_size(&points, &folds);
+    if let Some((line, _)) = dot_lines.iter().zip(&points).find(|(_, &(x, y))| x >= width || y >= height) {
+        return Err(line.invalid(line.text, &format!("a dot on the sheet of {} by {} that the folds define", width, height)));
+    }
+    // every fold must lie on the sheet as the folds before it left it
+    for (line, (axis, index)) in fold_lines.iter().zip(&folds) {
+        let size = if axis == "x" { &mut width } else { &mut height };
+        if *index >= *size {
+            return Err(line.invalid(line.text, &format!("a fold line within the sheet, which is {} long along {}", size, axis)));
+        }
+        *size = *index;
+    }
+    Ok((points, folds))
+}
+
+fn parse_fold(line: Line) -> Result<Fold, ParseError> {
+    let instruction = line.text.strip_prefix("fold along ")
+        .ok_or_else(|| line.invalid(line.text, "'fold along'"))?;
+    let (axis, index) = split_pair(line, instruction, "=", "a line index")?;
+    if axis != "x" && axis != "y" {
+        return Err(line.invalid(axis, "axis x or y"));
+    }
+    Ok((axis.to_string(), line.int(index)?))
+}
+
+struct Sheet(Grid<bool>);
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day13;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn test_example() {
+        let input = Day13::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("17", Day13::part_a(&input).to_string());
+        let square = "    01234\n0  |█████\n1  |█...█\n2  |█...█\n3  |█...█\n4  |█████\n5  |.....\n6  |.....\n";
+        assert_eq!(square, Day13::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_invalid_sheet() {
+        assert_eq!("<input>:2:1: missing fold instructions", Day13::parse(example_lines("1,2\n")).unwrap_err().to_string());
+        assert_eq!("<input>:2:1: expected a dot on the sheet of 11 by 5 that the folds define, found '3,5'",
+                   Day13::parse(example_lines("1,2\n3,5\n\nfold along x=5\nfold along y=2")).unwrap_err().to_string());
+        assert_eq!("<input>:4:1: expected a fold line within the sheet, which is 5 long along x, found 'fold along x=5'",
+                   Day13::parse(example_lines("1,2\n\nfold along x=5\nfold along x=5")).unwrap_err().to_string());
+
+        let input = Day13::parse(example_lines("1,2\n9,4\n\nfold along x=5")).unwrap();
+        assert_eq!("2", Day13::part_a(&input).to_string());
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use itertools::{Itertools, MinMaxResult};
+use rayon::prelude::*;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::Line;
+use crate::parse::{blocks, split_pair};
+use crate::solution::{Output, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<char>, Vec<Rule>);
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        read_input(text)
+    }
+
+    fn part_a((template, rules): &Self::Input) -> Output {
+        task(template, rules, 10).into()
+    }
+
+    fn part_b((template, rules): &Self::Input) -> Output {
+        task(template, rules, 40).into()
+    }
+}
+
+impl Generate for Day14 {
+    /// Length of the polymer template. There is a rule for every pair of its 10 elements.
+    const SIZE: usize = 20;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut elements = ('A'..='Z').collect_vec();
+        rng.shuffle(&mut elements);
+        elements.truncate(10);
+        let template = (0..size.max(2)).map(|_| *rng.choose(&elements)).collect::<String>();
+        let mut rules = elements.iter().cartesian_product(&elements)
+            .map(|(a, b)| format!("{}{} -> {}\n", a, b, rng.choose(&elements)))
+            .collect_vec();
+        rng.shuffle(&mut rules);
+        format!("{}\n\n{}", template, rules.concat())
+    }
+}
+
+fn task(template: &[char], rules: &[Rule], steps: usize) -> usize {
+    let rule_map = rules.iter().map(|r|(r.0, *r)).collect::<HashMap<_,_>>();
+    let seed = template.iter().tuple_windows().collect_vec().into_iter().map(|(a, b)|[*a, *b]);
+    let mut stats = seed.collect_vec().par_iter()
+         .map(|seed| {
+             recursive_edit(seed, steps,&rule_map,&mut Cache::new())
+         }).reduce_with(|a: Stats, b: Stats| merge_stats(&a,&b))
+        .expect("reduce failed");
+
+    *stats.entry(template[template.len()-1]).or_insert(0) += 1;
+    score(&stats)
+}
+
+fn single_edit(pair: &[char; 2], rules: &Rules) -> Vec<Element>  {
+    match rules.get(pair) {
+        None => vec![*pair],
+        Some((_, insert)) => vec![[pair[0], *insert],[*insert, pair[1]]],
+    }
+}
+
+fn recursive_edit(
+    element: &Element,
+    step: usize,
+    rules: &Rules,
+    cache: &mut Cache) -> Stats {
+
+    let key = cache_key(element, &step);
+    if let Some(cached) = cache.get(&key){
+        cached.clone()
+    } else {
+        let stats = if step - 1 > 0  {
+            let edit = single_edit(element, rules);
+            edit.iter()
+                .map(|e|recursive_edit(e, step-1, rules, cache))
+                .reduce(|a,b|merge_stats(&a, &b))
+                .expect("reduce failed")
+        } else {
+            let edit = single_edit(element, rules);
+            stats(&edit)
+        };
+
+        cache.insert(key, stats.clone());
+        stats
+    }
+}
+
+
+fn stats(elements: &[Element]) -> Stats {
+    elements.iter().map(|pair|pair[0])
+        .fold(Stats::new(), |mut stat,c| {
+            *stat.entry(c).or_insert(0) += 1;
+            stat
+        })
+}
+
+fn merge_stats(a: &Stats, b: &Stats) -> Stats {
+    let mut m = a.clone();
+    b.iter().for_each(|(k,v)| *m.entry(*k).or_insert(0) += v);
+    m
+}
+
+fn cache_key(element: &Element, step: &usize) -> String {
+    format!("{}{}#{}", element[0], element[1], step)
+}
+
+
+fn score(stat: &HashMap<char,usize>) -> usize {
+    let  result= stat.iter().minmax_by_key(|(_,count)|**count);
+    match result {
+        MinMaxResult::MinMax((_ , min), (_, max)) => max-min,
+        MinMaxResult::OneElement(_) => 0,
+        MinMaxResult::NoElements => panic!("no elements to score")
+    }
+}
+
+type Rule = ([char;2], char);
+type Rules = HashMap<[char;2], Rule>;
+type Element = [char;2];
+type Stats = HashMap<char, usize>;
+type Cache = HashMap<String, Stats>;
+
+
+fn read_input(text: &str) -> Result<(Vec<char>, Vec<Rule>), ParseError> {
+    let mut blocks = blocks(text);
+    let template = match blocks.next().as_deref() {
+        Some([line]) if line.text.chars().count() >= 2 => line.text.chars().collect_vec(),
+        Some([_, line, ..]) => return Err(line.invalid(line.text, "a blank line after the polymer template")),
+        _ => return Err(ParseError::missing(1, "", "a polymer template")),
+    };
+    let rules = blocks.flatten()
+        .map(parse_rule)
+        .collect::<Result<_, _>>()?;
+    Ok((template, rules))
+}
+
+fn parse_rule(line: Line) -> Result<Rule, ParseError> {
+    let (pair, insert) = split_pair(line, line.text, " -> ", "an element")?;
+    match (pair.chars().collect_tuple(), insert.chars().collect_tuple()) {
+        (Some((a, b)), Some((i,))) => Ok(([a, b], i)),
+        (None, _) => Err(line.invalid(pair, "a pair of two elements")),
+        (_, None) => Err(line.invalid(insert, "a single element")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use itertools::Itertools;
+    use proptest::collection::btree_map;
+    use proptest::prelude::*;
+    use crate::input::{example_lines, parse_example};
+    use crate::solution::Solution;
+    use super::{task, Day14};
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn test_example() {
+        let input = Day14::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("1588", Day14::part_a(&input).to_string());
+        assert_eq!("2188189693529", Day14::part_b(&input).to_string());
+    }
+
+    /// Grows the polymer itself instead of counting its pairs.
+    fn expand_polymer(template: &str, rules: &BTreeMap<(char, char), char>, steps: usize) -> usize {
+        let mut polymer = template.chars().collect_vec();
+        for _ in 0..steps {
+            polymer = polymer.iter().tuple_windows()
+                .flat_map(|(&a, &b)| std::iter::once(a).chain(rules.get(&(a, b)).copied()))
+                .chain(polymer.last().copied())
+                .collect();
+        }
+        match polymer.iter().counts().values().minmax().into_option() {
+            Some((min, max)) => max - min,
+            None => 0,
+        }
+    }
+
+    fn element() -> impl Strategy<Value=char> {
+        prop::sample::select(vec!['A', 'B', 'C'])
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_string_expansion(template in "[ABC]{2,6}", rules in btree_map((element(), element()), element(), 0..=9), steps in 1..=6usize) {
+            let rule_lines = rules.iter().map(|((a, b), i)| format!("{}{} -> {}", a, b, i));
+            let (parsed_template, parsed_rules) = parse_example::<Day14>([template.clone(), String::new()].into_iter().chain(rule_lines));
+            prop_assert_eq!(expand_polymer(&template, &rules, steps), task(&parsed_template, &parsed_rules, steps));
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use itertools::{Itertools,};
+use pathfinding::prelude::astar;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::Grid;
+use crate::input::lines;
+use crate::parse::digit_grid;
+use crate::solution::{Output, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<usize>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        digit_grid(lines(text))
+    }
+
+    fn part_a(grid: &Self::Input) -> Output {
+        task(grid, false).into()
+    }
+
+    fn part_b(grid: &Self::Input) -> Output {
+        task(grid, true).into()
+    }
+}
+
+impl Generate for Day15 {
+    /// Side of the square risk map.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        Grid::from_fn(size.max(1), size.max(1), |_| rng.range(1..=9)).to_string() + "\n"
+    }
+}
+
+fn task(grid: &Grid<usize>, large_map: bool) -> usize {
+    let grid = if large_map { tile(grid, 5) } else { grid.clone() };
+    let start = (0usize, 0usize);
+    let goal = (grid.width() - 1, grid.height() - 1);
+
+    let (_, risk) = astar(&start,
+                             |&p| grid.neighbours_4(p).map(|n| (n, grid[n])).collect_vec(),
+                             |&(x, y)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) / 3,
+                             |&p| p == goal).unwrap();
+
+    risk
+}
+
+fn tile(grid: &Grid<usize>, times: usize) -> Grid<usize> {
+    let (width, height) = (grid.width(), grid.height());
+    Grid::from_fn(width * times, height * times, |(x, y)| {
+        (grid[(x % width, y % height)] + x / width + y / height - 1) % 9 + 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day15;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn test_example() {
+        let input = Day15::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("40", Day15::part_a(&input).to_string());
+        assert_eq!("315", Day15::part_b(&input).to_string());
+    }
+}
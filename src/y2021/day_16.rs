@@ -0,0 +1,280 @@
+use itertools::{Itertools,};
+use self::Body::Literal;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        let hex = read_input(lines)?;
+        let mut reader = BitsReader::new(&hex);
+        reader.read_packet()
+    }
+
+    fn part_a(packet: &Self::Input) -> Output {
+        packet.version_sum().into()
+    }
+
+    fn part_b(packet: &Self::Input) -> Output {
+        packet.value().into()
+    }
+}
+
+impl Generate for Day16 {
+    /// Number of packets in the transmission.
+    const SIZE: usize = 250;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // the outermost packet is an operator, so there are at least two packets
+        let (mut bits, _) = random_packet(rng, size.max(2));
+        while bits.len() % 4 != 0 {
+            bits.push('0');
+        }
+        (0..bits.len()).step_by(4).map(|i| format!("{:X}", parse_bin(&bits[i..i + 4]))).collect::<String>() + "\n"
+    }
+}
+
+/// Values of generated packets stay below this, so that they add up and multiply without overflowing.
+const MAX_VALUE: usize = 1 << 48;
+
+/// The bits and value of a random packet made of `packets` packets including itself, a literal
+/// only if it has no sub-packets left to contain.
+fn random_packet(rng: &mut Rng, packets: usize) -> (String, usize) {
+    let version = rng.below(8);
+    if packets <= 1 {
+        let bits = rng.range(4..=16);
+        let value = rng.below(1 << bits);
+        let nibbles = format!("{:x}", value);
+        let groups = nibbles.chars().enumerate()
+            .map(|(i, nibble)| format!("{}{:04b}", (i + 1 < nibbles.len()) as u8, nibble.to_digit(16).expect("a hex digit")))
+            .join("");
+        return (format!("{:03b}100{}", version, groups), value);
+    }
+    let remaining = packets - 1;
+    let type_id = *rng.choose(if remaining < 2 { &[0, 1, 2, 3][..] } else { &[0, 1, 2, 3, 5, 6, 7] });
+    let count = if type_id >= 5 { 2 } else { rng.range(1..=4.min(remaining as i64)) as usize };
+    // every sub-packet gets at least one of the remaining packets and the rest at random
+    let mut shares = vec![1; count];
+    for _ in count..remaining {
+        shares[rng.below(count)] += 1;
+    }
+    let (sub_packets, values): (Vec<_>, Vec<_>) = shares.into_iter().map(|share| random_packet(rng, share)).unzip();
+    let value = |type_id| match type_id {
+        0 => values.iter().try_fold(0usize, |sum, v| sum.checked_add(*v)),
+        1 => values.iter().try_fold(1usize, |product, v| product.checked_mul(*v)),
+        2 => values.iter().min().copied(),
+        3 => values.iter().max().copied(),
+        5 => Some((values[0] > values[1]) as usize),
+        6 => Some((values[0] < values[1]) as usize),
+        _ => Some((values[0] == values[1]) as usize),
+    }.filter(|&value| value < MAX_VALUE);
+    // sums and products that grow too large become minimums
+    let (type_id, value) = match value(type_id) {
+        Some(value) => (type_id, value),
+        None => (2, value(2).expect("the minimum of small values is small")),
+    };
+    let sub_packets = sub_packets.concat();
+    let length = if sub_packets.len() < 1 << 15 && rng.chance(0.5) {
+        format!("0{:015b}", sub_packets.len())
+    } else {
+        format!("1{:011b}", count)
+    };
+    (format!("{:03b}{:03b}{}{}", version, type_id, length, sub_packets), value)
+}
+
+pub struct BitsReader<'a> {
+    hex: &'a str,
+    bits: Box<dyn Iterator<Item=usize> + 'a>,
+    read_bits_count: usize
+}
+
+impl <'a>BitsReader<'a> {
+
+    pub fn new(hex: &'a str) -> BitsReader<'a> {
+        let bits = Box::new(hex.chars()
+            .flat_map(|c|hex_to_binary(&c)));
+        BitsReader {hex, bits, read_bits_count: 0}
+    }
+
+    pub fn read_packet(&mut self) -> Result<Packet, ParseError> {
+        let header = self.read_header()?;
+        let content = match header.type_id {
+            0 => Body::Operator(Op::Sum, self.read_operator_body()?),
+            1 => Body::Operator(Op::Prod,self.read_operator_body()?),
+            2 => Body::Operator(Op::Min,self.read_operator_body()?),
+            3 => Body::Operator(Op::Max,self.read_operator_body()?),
+            4 => Body::Literal(self.read_literal_value()?),
+            5 => Body::Operator(Op::Gt,self.read_operator_body()?),
+            6 => Body::Operator(Op::Lt,self.read_operator_body()?),
+            7 => Body::Operator(Op::Eq,self.read_operator_body()?),
+            _ => unreachable!("type id has 3 bits")
+        };
+        Ok(Packet{ header, body: content })
+    }
+
+    fn read_bits(&mut self, count: usize) -> Result<usize, ParseError> {
+        let bits = self.bits.by_ref().take(count).join("");
+        if bits.len() < count {
+            return Err(ParseError::missing(1, self.hex, "more packet bits"));
+        }
+        self.read_bits_count += count;
+        Ok(parse_bin(&bits))
+    }
+
+    fn read_literal_value(&mut self) -> Result<usize, ParseError> {
+        let mut value = 0;
+        loop {
+            let group = self.read_bits(5)?;
+            value = value << 4 | group & 0b1111;
+            if group >> 4 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn read_header(&mut self) -> Result<Header, ParseError> {
+        Ok(Header{
+            version: self.read_bits(3)?,
+            type_id: self.read_bits(3)?
+        })
+    }
+
+    fn read_length_type_0_body(&mut self) -> Result<Vec<Packet>, ParseError> {
+        let length = self.read_bits(15)?;
+        let mut packets = vec![];
+        let start_count = self.read_bits_count;
+        while self.read_bits_count - start_count < length {
+            packets.push(self.read_packet()?);
+        }
+        Ok(packets)
+    }
+
+    fn read_length_type_1_body(&mut self) -> Result<Vec<Packet>, ParseError> {
+        let count = self.read_bits(11)?;
+        (0..count).map(|_|self.read_packet()).collect()
+    }
+
+    fn read_operator_body(&mut self) -> Result<Vec<Packet>, ParseError> {
+        match self.read_bits(1)? {
+            0 => self.read_length_type_0_body(),
+            _ => self.read_length_type_1_body(),
+        }
+    }
+}
+
+
+fn hex_to_binary(hex: &char) -> [usize;4] {
+    match hex {
+        '0' => [0,0,0,0],
+        '1' => [0,0,0,1],
+        '2' => [0,0,1,0],
+        '3' => [0,0,1,1],
+        '4' => [0,1,0,0],
+        '5' => [0,1,0,1],
+        '6' => [0,1,1,0],
+        '7' => [0,1,1,1],
+        '8' => [1,0,0,0],
+        '9' => [1,0,0,1],
+        'A' => [1,0,1,0],
+        'B' => [1,0,1,1],
+        'C' => [1,1,0,0],
+        'D' => [1,1,0,1],
+        'E' => [1,1,1,0],
+        'F' => [1,1,1,1],
+        _ => unreachable!("hex digits are validated by read_input")
+    }
+}
+
+impl Packet {
+    pub fn version_sum(&self) -> usize {
+        self.header.version + match &self.body {
+             Literal(_) => 0,
+             Body::Operator(_, v) => v.iter().map(|p|p.version_sum()).sum()
+        }
+    }
+
+    pub fn value(&self) -> usize {
+        match &self.body {
+            Literal(v) => *v,
+            Body::Operator(o, v) => match o {
+                Op::Min => v.iter().map(|p|p.value()).min().unwrap(),
+                Op::Max => v.iter().map(|p|p.value()).max().unwrap(),
+                Op::Sum => v.iter().map(|p|p.value()).sum(),
+                Op::Prod => v.iter().map(|p|p.value()).product(),
+                Op::Gt => if v[0].value() > v[1].value() {1} else {0},
+                Op::Lt => if v[0].value() < v[1].value() {1} else {0},
+                Op::Eq => if v[0].value() == v[1].value() {1} else {0},
+            }
+        }
+    }
+}
+#[derive(Debug, PartialEq)]
+pub struct Packet {
+    header: Header,
+    body: Body
+}
+
+#[derive(Debug, PartialEq)]
+enum Op {Sum, Prod, Min, Max, Gt, Lt, Eq}
+
+#[derive(Debug, PartialEq)]
+enum Body {
+    Literal(usize), Operator(Op, Vec<Packet>)
+}
+
+#[derive(Debug, PartialEq)]
+struct Header {
+    version: usize,
+    type_id: usize
+}
+
+fn read_input(mut lines: impl Iterator<Item=String>) -> Result<String, ParseError> {
+    let line = lines.next().ok_or_else(|| ParseError::missing(1, "", "a hexadecimal transmission"))?;
+    match line.char_indices().find(|(_, c)| !matches!(c, '0'..='9' | 'A'..='F')) {
+        Some((i, c)) => Err(ParseError::invalid(1, &line, &line[i..i + c.len_utf8()], "a hexadecimal digit")),
+        None => Ok(line),
+    }
+}
+
+fn parse_bin(s: &str) -> usize {
+    usize::from_str_radix(s, 2).expect("parse failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{Generate, Rng};
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day16;
+
+    #[test]
+    fn test_version_sums() {
+        for (packet, sum) in [("8A004A801A8002F478", "16"), ("620080001611562C8802118E34", "12"),
+                              ("C0015000016115A2E0802F182340", "23"), ("A0016C880162017C3686B18A3D4780", "31")] {
+            let input = Day16::parse(example_lines(packet)).unwrap();
+            assert_eq!(sum, Day16::part_a(&input).to_string(), "{}", packet);
+        }
+    }
+
+    #[test]
+    fn test_values() {
+        for (packet, value) in [("C200B40A82", "3"), ("04005AC33890", "54"), ("880086C3E88112", "7"), ("CE00C43D881120", "9"),
+                                ("D8005AC2A8F0", "1"), ("F600BC2D8F", "0"), ("9C005AC2F8F0", "0"), ("9C0141080250320F1802104A08", "1")] {
+            let input = Day16::parse(example_lines(packet)).unwrap();
+            assert_eq!(value, Day16::part_b(&input).to_string(), "{}", packet);
+        }
+    }
+
+    #[test]
+    fn test_generate_grows_with_size() {
+        for seed in 0..20 {
+            let lengths = [1, 10, 100, 1000].map(|size| Day16::generate(&mut Rng::new(seed), size).trim().len());
+            assert!(lengths[0] > 4, "seed {} gave {:?}", seed, lengths);
+            assert!(lengths.windows(2).all(|pair| pair[0] < pair[1]), "seed {} gave {:?}", seed, lengths);
+        }
+    }
+}
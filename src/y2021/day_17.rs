@@ -0,0 +1,136 @@
+use itertools::{Itertools};
+use crate::error::{expect_token, parse_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Launcher;
+
+    fn parse(mut lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        let line = lines.next().ok_or_else(|| ParseError::missing(1, "", "a target area"))?;
+        let ranges = line.strip_prefix("target area: ")
+            .ok_or_else(|| ParseError::invalid(1, &line, &line, "'target area: x=.., y=..'"))?;
+        let mut ranges = ranges.split(", ");
+        let target_x = parse_range(&line, expect_token(1, &line, ranges.next(), "an x range")?, "x=")?;
+        let target_y = parse_range(&line, expect_token(1, &line, ranges.next(), "a y range")?, "y=")?;
+        Ok(Launcher { target_x, target_y })
+    }
+
+    fn part_a(launcher: &Self::Input) -> Output {
+        max_height(launcher.target_y.0).into()
+    }
+
+    fn part_b(launcher: &Self::Input) -> Output {
+        launcher.all_possible_hit_velocities().len().into()
+    }
+}
+
+impl Generate for Day17 {
+    /// Distance of the far end of the target area, both horizontally and downwards.
+    const SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // some x velocity comes to a halt above the target, so the highest shot drops straight into it
+        let size = size.max(4) as i64;
+        let halt = rng.range(1..=((2 * size) as f64).sqrt() as i64 - 1);
+        let distance = halt * (halt + 1) / 2;
+        let (x1, x2) = ((distance - rng.range(0..=halt)).max(1), distance + rng.range(0..=halt));
+        let y1 = -rng.range(size / 2..=size);
+        let y2 = (y1 + rng.range(0..=size / 2)).min(-1);
+        format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2)
+    }
+}
+
+fn parse_range(line: &str, range: &str, prefix: &str) -> Result<(isize, isize), ParseError> {
+    let bounds = range.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::invalid(1, line, range, &format!("'{}from..to'", prefix)))?;
+    let mut bounds = bounds.split("..");
+    let from = parse_token(1, line, expect_token(1, line, bounds.next(), "a lower bound")?)?;
+    let to = parse_token(1, line, expect_token(1, line, bounds.next(), "'..' followed by an upper bound")?)?;
+    Ok((from, to))
+}
+
+fn max_height(min_y: isize) -> isize {
+    (min_y * (min_y + 1)) / 2
+}
+
+pub struct Launcher {
+    target_x: (isize, isize),
+    target_y: (isize, isize),
+}
+
+impl Launcher {
+    fn will_hit_target(&self, initial_v: (isize, isize)) -> Option<(isize, isize)> {
+        let (mut v, mut pos) = (initial_v, (0, 0));
+        loop {
+            pos.0 += v.0; pos.1 += v.1;
+            v.0 = (v.0 - 1).max(0); v.1 -= 1;
+
+            let out_of_range = pos.0 > self.target_x.1 || pos.1 < self.target_y.0;
+
+            if self.in_target(pos) {  return Some(initial_v); }
+            else if out_of_range { return None; }
+        }
+    }
+
+    fn will_hit_x(&self, initial_v: isize) -> Option<isize> {
+        let (mut v, mut pos) = (initial_v, 0);
+        loop {
+            pos += v; v = (v - 1).max(0);
+            if self.is_x_hit(pos) { return Some(initial_v); }
+            else if v == 0 || pos > self.target_x.1 { return None; }
+        }
+    }
+
+    fn will_hit_y(&self, initial_v: isize) -> Option<isize> {
+        let (mut v, mut pos) = (initial_v, 0);
+        loop {
+            pos += v; v -= 1;
+            if self.is_y_hit(pos) { return Some(initial_v); }
+            else if pos < self.target_y.0 { return None; }
+        }
+    }
+
+    fn all_possible_hit_velocities(&self) -> Vec<(isize, isize)> {
+        let x_hits =
+            (1..self.target_x.1 + 1).filter_map(|x| self.will_hit_x(x));
+        let y_hits =
+            (self.target_y.0..-self.target_y.0 + 1).filter_map(|y| self.will_hit_y(y));
+
+        x_hits.cartesian_product(y_hits)
+        .filter_map(|v| self.will_hit_target(v))
+        .collect()
+    }
+
+
+    fn is_x_hit(&self, x: isize) -> bool {
+        x >= self.target_x.0 && x <= self.target_x.1
+    }
+
+    fn is_y_hit(&self, y: isize) -> bool {
+        y >= self.target_y.0 && y <= self.target_y.1
+    }
+
+    fn in_target(&self, p: (isize, isize)) -> bool {
+        self.is_x_hit(p.0) && self.is_y_hit(p.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day17;
+
+    const EXAMPLE: &str = "\
+target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn test_example() {
+        let input = Day17::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("45", Day17::part_a(&input).to_string());
+        assert_eq!("112", Day17::part_b(&input).to_string());
+    }
+}
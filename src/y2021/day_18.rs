@@ -0,0 +1,191 @@
+use std::iter;
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Number>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        parse_lines(lines)
+    }
+
+    fn part_a(numbers: &Self::Input) -> Output {
+        let sum = numbers.iter()
+            .cloned()
+            .reduce(|a,b| add(&a, &b))
+            .expect("reduce failed");
+        Magnitude {n: Box::new(sum.into_iter())} .calculate().into()
+    }
+
+    fn part_b(numbers: &Self::Input) -> Output {
+        numbers.iter().cartesian_product(numbers.iter())
+            .filter(|(a,b)|a!=b)
+            .flat_map(|(a, b)| vec![add(a, b), add(b, a)])
+            .map(|n|reduce(&n))
+            .map(|n|Magnitude {n: Box::new(n.into_iter())} .calculate())
+            .max().unwrap().into()
+    }
+}
+
+impl Generate for Day18 {
+    /// Number of distinct snailfish numbers, all of them reduced.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        iter::repeat_with(|| random_element(rng, 0))
+            .unique()
+            .take(size.max(2))
+            .map(|number| number + "\n")
+            .collect()
+    }
+}
+
+/// A random element nested in `depth` pairs: a pair if it is the outermost one, and a regular
+/// number if a pair would explode.
+fn random_element(rng: &mut Rng, depth: usize) -> String {
+    if depth == 4 || depth > 0 && rng.chance(0.3) {
+        rng.below(10).to_string()
+    } else {
+        format!("[{},{}]", random_element(rng, depth + 1), random_element(rng, depth + 1))
+    }
+}
+
+type Number = Vec<isize>;
+
+fn parse_lines(lines: impl Iterator<Item=String>) -> Result<Vec<Number>, ParseError> {
+    (1..).zip(lines).map(|(n, l)| parse_line(n, &l)).collect()
+}
+
+fn parse_line(n: usize, line: &str) -> Result<Number, ParseError> {
+    line.char_indices().map(|(i, c)| match c {
+        '[' => Ok(-1),
+        ']' => Ok(-2),
+        ',' => Ok(-3),
+        _ => c.to_digit(10)
+            .map(|d| d as isize)
+            .ok_or_else(|| ParseError::invalid(n, line, &line[i..i + c.len_utf8()], "a bracket, comma or digit"))
+    }).collect()
+}
+
+fn add(lhs: &Number, rhs: &Number) -> Number {
+    let sum = [vec![-1],lhs.clone(), vec![-3], rhs.clone(), vec![-2]]
+        .concat();
+    reduce(&sum)
+}
+
+fn explode(n: &Number) -> Number {
+
+    let mut n = n.clone();
+    let most_left = n.iter().scan((0,0), |s ,v| {
+        match v { -1 => s.1 += 1, -2 => s.1 -= 1, _ => {} };
+        let r = Some(*s);
+        s.0 +=1;
+        r
+    })
+        .filter(|(_, s)| *s==5)
+        .map(|(i, _)| i)
+        .next();
+
+    if let Some(i) = most_left {
+        let left = n[i+1];
+        let right = n[i+3];
+        n.remove(i);
+        n.remove(i);
+        n.remove(i);
+        n.remove(i);
+        n[i] = 0;
+        if let Some(v) = n[..i].iter_mut().rev().find(|v| **v >= 0) {
+            *v += left;
+        }
+        if let Some(v) = n[i+1..].iter_mut().find(|v| **v >= 0) {
+            *v += right;
+        }
+    }
+    n
+}
+
+fn reduce(n: &Number) -> Number {
+    let mut last = n.clone();
+
+    loop {
+        let e = explode(&last);
+        if e != last {
+            last = e;
+            continue
+        }
+        let s = split(&last);
+        if s!=last {
+            last = s;
+            continue
+        }
+        break;
+    }
+    last
+}
+
+fn split(n: &Number) -> Number {
+    n.iter().scan((false, false), |s ,v| {
+        s.0 = !s.1 && *v >= 10;
+        s.1 = s.1 || s.0;
+        Some((s.0,v))
+    }).flat_map(|(s, v)| {
+        if s {
+            vec![-1, (*v as f64 / 2.).floor() as isize, -3,
+                 (*v as f64 / 2.).ceil() as isize, -2]
+        } else {
+            vec![*v]
+        }
+    }
+    ).collect()
+}
+
+
+struct Magnitude {
+    n: Box<dyn Iterator<Item=isize>>
+}
+
+impl Magnitude {
+    fn calculate(&mut self) -> isize {
+        match self.n.next() {
+            Some(-1) => {
+                let left = self.calculate();
+                self.n.next().unwrap();
+                let right= self.calculate();
+                self.n.next().unwrap();
+                3 * left + 2 * right
+            },
+            Some(v) => v,
+            _ => panic!("not expected")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day18;
+
+    const EXAMPLE: &str = "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    #[test]
+    fn test_example() {
+        let input = Day18::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("4140", Day18::part_a(&input).to_string());
+        assert_eq!("3993", Day18::part_b(&input).to_string());
+    }
+}
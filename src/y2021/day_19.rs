@@ -1,24 +1,108 @@
+use std::collections::BTreeSet;
 use std::ops::Index;
+use std::sync::OnceLock;
 use itertools::{Itertools};
+use tracing::debug;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::join_lines;
+use crate::parse::{blocks, coords, header};
+use crate::solution::{Output, Solution};
 
-use crate::lib::read_lines;
+pub struct Day19;
 
-mod lib;
+impl Solution for Day19 {
+    type Input = Scans;
 
-fn main() {
-    let report = Report::parse(read_lines("input/day_19.txt")).normalize();
-    let task_a = report.unique_beacons().len();
-    let task_b= report.max_manhattan_distance();
-    assert_eq!(376, task_a);
-    assert_eq!(10772, task_b);
-    println!("task_a: {}, task_b: {}", task_a, task_b);
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        Ok(Scans { report: Report::parse_text(text)?, normalized: OnceLock::new() })
+    }
+
+    fn part_a(scans: &Self::Input) -> Output {
+        scans.normalized().unique_beacons().len().into()
+    }
+
+    fn part_b(scans: &Self::Input) -> Output {
+        scans.normalized().max_manhattan_distance().into()
+    }
+}
+
+impl Generate for Day19 {
+    /// Number of scanners. Each one shares at least 12 beacons with one of the scanners before it.
+    const SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut scanners = vec![];
+        let mut beacons = BTreeSet::new();
+        for i in 0..size.max(2) {
+            let scanner = match i {
+                0 => [0, 0, 0],
+                _ => {
+                    let neighbour: [i64; 3] = scanners[rng.below(i)];
+                    let scanner = neighbour.map(|c| c + rng.range(-1200..=1200));
+                    let from = [0, 1, 2].map(|a| scanner[a].max(neighbour[a]) - 1000);
+                    let to = [0, 1, 2].map(|a| scanner[a].min(neighbour[a]) + 1000);
+                    while beacons.iter().filter(|b| in_range(&scanner, b) && in_range(&neighbour, b)).count() < 12 {
+                        beacons.insert([0, 1, 2].map(|a| rng.range(from[a]..=to[a])));
+                    }
+                    scanner
+                }
+            };
+            for _ in 0..rng.range(5..=15) {
+                beacons.insert(scanner.map(|c| c + rng.range(-1000..=1000)));
+            }
+            scanners.push(scanner);
+        }
+        scanners.iter().enumerate()
+            .map(|(i, scanner)| {
+                let (axes, signs) = random_rotation(rng);
+                let mut seen = beacons.iter()
+                    .filter(|b| in_range(scanner, b))
+                    .map(|b| [0, 1, 2].map(|a| (b[axes[a]] - scanner[axes[a]]) * signs[a]))
+                    .collect_vec();
+                rng.shuffle(&mut seen);
+                format!("--- scanner {} ---\n{}", i, seen.iter().map(|b| format!("{},{},{}\n", b[0], b[1], b[2])).join(""))
+            })
+            .join("\n")
+    }
+}
+
+fn in_range(scanner: &[i64; 3], beacon: &[i64; 3]) -> bool {
+    (0..3).all(|a| scanner[a].abs_diff(beacon[a]) <= 1000)
+}
+
+/// One of the 24 ways a scanner can face, as the axis and sign that each of its coordinates has.
+fn random_rotation(rng: &mut Rng) -> ([usize; 3], [i64; 3]) {
+    loop {
+        let mut axes = [0, 1, 2];
+        rng.shuffle(&mut axes);
+        let signs = [(); 3].map(|_| *rng.choose(&[-1, 1]));
+        // turning never mirrors, so swapping two axes flips the sign of one of them
+        let swapped = ![[0, 1, 2], [1, 2, 0], [2, 0, 1]].contains(&axes);
+        if swapped == (signs.iter().product::<i64>() < 0) {
+            return (axes, signs);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
-struct Report {
+pub struct Report {
     scanners: Vec<Scanner>,
 }
 
+/// A report as parsed, normalized by whichever part needs it first.
+#[derive(Debug)]
+pub struct Scans {
+    report: Report,
+    normalized: OnceLock<Report>,
+}
+
+impl Scans {
+    pub fn normalized(&self) -> &Report {
+        self.normalized.get_or_init(|| self.report.normalize())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 struct Position {
     x: isize,
@@ -35,7 +119,7 @@ struct Scanner {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
-struct Beacon {
+pub struct Beacon {
     position: Position,
 }
 
@@ -62,6 +146,7 @@ impl From<Vec<isize>> for Position {
     }
 }
 
+
 impl Index<usize> for Position {
     type Output = isize;
 
@@ -77,12 +162,12 @@ impl Index<usize> for Position {
 
 impl Orientation {
     fn possible_orientations() -> Vec<Self> {
-        let flip_permutations = vec![
+        let flip_permutations = [
             vec![ 1, 1, 1], vec![ 1,-1,-1], vec![-1,-1, 1], vec![-1, 1,-1],
             vec![-1,-1,-1], vec![ 1, 1,-1], vec![ 1,-1, 1], vec![-1, 1, 1],
         ];
         let axis_permutations = vec![0, 1, 2].into_iter().permutations(3).collect_vec();
-        flip_permutations.iter().map(|flip| axis_permutations.iter().map(|axis| {
+        flip_permutations.iter().flat_map(|flip| axis_permutations.iter().map(|axis| {
             Orientation {
                 x_flip: flip[0],
                 y_flip: flip[1],
@@ -91,7 +176,7 @@ impl Orientation {
                 y_axis_idx: axis[1],
                 z_axis_idx: axis[2],
             }
-        })).flatten().collect()
+        })).collect()
     }
 
     fn identity() -> Orientation {
@@ -193,27 +278,27 @@ impl Scanner {
 
 
 impl Report {
-    fn parse(lines: impl Iterator<Item=String>) -> Self {
-        let mut lines = lines.peekable();
+    pub fn parse(lines: impl Iterator<Item=String>) -> Result<Self, ParseError> {
+        Self::parse_text(&join_lines(lines))
+    }
 
+    pub fn parse_text(text: &str) -> Result<Self, ParseError> {
         let mut scanners = vec![];
-        while lines.peek().is_some() {
-            let mut scanner_lines = lines.by_ref().take_while(|l| !l.is_empty());
-            let id = scanner_lines.next().unwrap()
-                .split_whitespace().nth(2).unwrap()
-                .parse::<usize>().unwrap();
-
-            let beacons = scanner_lines
-                .map(|l| l.split(',').map(|c| c.parse::<isize>().unwrap()).collect_vec())
-                .map(|v| Beacon { position: Position::from(v) })
-                .collect();
-
+        for block in blocks(text) {
+            let id = header(block[0], "--- scanner ", " ---")?;
+            let beacons = block[1..].iter()
+                .map(|&line| coords(line, line.text, ["an x coordinate", "a y coordinate", "a z coordinate"])
+                    .map(|[x, y, z]| Beacon { position: Position { x, y, z } }))
+                .collect::<Result<_, _>>()?;
             scanners.push(Scanner::new(id, beacons));
         }
-        Self { scanners }
+        if scanners.is_empty() {
+            return Err(ParseError::missing(1, "", "a scanner report"));
+        }
+        Ok(Self { scanners })
     }
 
-    fn normalize(&self) -> Self {
+    pub fn normalize(&self) -> Self {
         let mut scanners = self.scanners.clone();
         let mut normalized_scanners = vec![scanners.pop().unwrap()];
 
@@ -226,7 +311,7 @@ impl Report {
                 match norm_scanner.overlap_with(&scanner) {
                     None => {}
                     Some((_, s)) => {
-                        println!("matched norm {} with {}", norm_scanner.id, scanner.id);
+                        debug!("matched normalized scanner {} with {}", norm_scanner.id, scanner.id);
                         had_match = true;
                         normalized_scanners.push(s.clone());
                         break
@@ -240,11 +325,11 @@ impl Report {
         Self{scanners: normalized_scanners}
     }
 
-    fn unique_beacons(&self) -> Vec<Beacon> {
+    pub fn unique_beacons(&self) -> Vec<Beacon> {
         self.scanners.iter().flat_map(|s|s.beacons()).sorted().dedup().collect_vec()
     }
 
-    fn max_manhattan_distance(&self) -> usize {
+    pub fn max_manhattan_distance(&self) -> usize {
         self.scanners.iter().map(|s|&s.translation).combinations(2)
             .map(|v| (v[0].x-v[1].x).abs() + (v[0].y-v[1].y).abs() + (v[0].z-v[1].z).abs())
             .max().unwrap() as usize
@@ -254,12 +339,20 @@ impl Report {
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
-    use crate::{Orientation, Position, read_lines, Report, Scanner, Translation};
+    use crate::store::{InputStore, Key};
+    use crate::solution::Solution;
+    use super::{Day19, Report, Translation};
 
+    #[test]
+    fn test_solution_example() {
+        let input = Day19::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
+        assert_eq!("79", Day19::part_a(&input).to_string());
+        assert_eq!("3621", Day19::part_b(&input).to_string());
+    }
 
     #[test]
     fn test_example_0_1() {
-        let report = Report::parse(read_lines("input/day_19_test.txt"));
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
         let (matches, scanner_1_rel_to_0) = report.scanners[0].overlap_with(&report.scanners[1]).unwrap();
         let matches = matches.iter().flat_map(|m|vec![m.position.x, m.position.y, m.position.z]).collect_vec();
         assert_eq!(matches, vec![-618,-824,-621, -537,-823,-458, -447,-329,318, 404,-588,-901, 544,-627,-890, 528,-643,409, -661,-816,-575, 390,-675,-793, 423,-701,434, -345,-311,381, 459,-707,401, -485,-357,347]);
@@ -269,7 +362,7 @@ mod tests {
 
     #[test]
     fn test_example_1_3() {
-        let report = Report::parse(read_lines("input/day_19_test.txt"));
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
         let (_, scanner_1_rel_to_0) = report.scanners[0].overlap_with(&report.scanners[1]).unwrap();
         let (m, s) = scanner_1_rel_to_0.overlap_with(&report.scanners[3]).unwrap();
         println!("{:?}", s.orientation);
@@ -280,8 +373,8 @@ mod tests {
 
     #[test]
     fn test_example_4_1() {
-        let report = Report::parse(read_lines("input/day_19_test.txt"));
-        let (_, s) = report.scanners[1].overlap_with(&report.scanners[4]).unwrap();
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
+        report.scanners[1].overlap_with(&report.scanners[4]).unwrap();
         let (_, s) = report.scanners[4].overlap_with(&report.scanners[1]).unwrap();
         println!("{:?}", s.orientation);
         println!("{:?}", s.translation);
@@ -289,8 +382,8 @@ mod tests {
 
     #[test]
     fn test_example_2_4() {
-        let report = Report::parse(read_lines("input/day_19_test.txt"));
-        let (_, s) = report.scanners[4].overlap_with(&report.scanners[2]).unwrap();
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
+        report.scanners[4].overlap_with(&report.scanners[2]).unwrap();
         let (_, s) = report.scanners[2].overlap_with(&report.scanners[4]).unwrap();
         println!("{:?}", s.orientation);
         println!("{:?}", s.translation);
@@ -299,7 +392,7 @@ mod tests {
 
     #[test]
     fn test_example_0_1_2_3_4() {
-        let report = Report::parse(read_lines("input/day_19_test.txt"));
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap();
         let n0 = report.scanners[0].clone();
         let (m1, n1) = report.scanners[0].overlap_with(&report.scanners[1]).unwrap();
         let (m2, n3) = n1.overlap_with(&report.scanners[3]).unwrap();
@@ -314,16 +407,15 @@ mod tests {
         assert_eq!(12, m2.len());
         assert_eq!(12, m3.len());
         assert_eq!(12, m4.len());
-        let beacons = vec![n0.beacons(), n1.beacons(), n2.beacons(), n3.beacons(), n4.beacons()].concat()
+        let beacons = [n0.beacons(), n1.beacons(), n2.beacons(), n3.beacons(), n4.beacons()].concat()
             .into_iter().sorted().dedup().collect_vec();
         assert_eq!(79, beacons.len());
     }
 
     #[test]
     fn test_normalize() {
-        let report = Report::parse(read_lines("input/day_19_test.txt")).normalize();
+        let report = Report::parse(InputStore::new("input").read_lines(&Key::example(2021, 19)).unwrap()).unwrap().normalize();
         assert_eq!(79, report.unique_beacons().len());
     }
 
 }
-
@@ -0,0 +1,180 @@
+use crate::error::{expect_token, parse_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        (1..).zip(lines)
+            .map(|(n, line)| Command::parse(n, &line))
+            .collect()
+    }
+
+    fn part_a(commands: &Self::Input) -> Output {
+        let end = Plain.navigate(commands);
+        (end.x * end.depth).into()
+    }
+
+    fn part_b(commands: &Self::Input) -> Output {
+        let end = Aimed.navigate(commands);
+        (end.x * end.depth).into()
+    }
+}
+
+impl Generate for Day2 {
+    /// Number of commands.
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // the submarine never rises above the surface, which also keeps the aim positive
+        let mut depth = 0;
+        (0..size.max(1))
+            .map(|_| {
+                let magnitude = rng.range(1..=9);
+                let direction = match rng.below(3) {
+                    0 => "forward",
+                    1 if depth >= magnitude => {
+                        depth -= magnitude;
+                        "up"
+                    }
+                    _ => {
+                        depth += magnitude;
+                        "down"
+                    }
+                };
+                format!("{} {}\n", direction, magnitude)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(isize),
+    Back(isize),
+    Down(isize),
+    Up(isize),
+    /// Returns to the starting point at the surface, with no aim.
+    Reset,
+}
+
+/// Position and aim of the submarine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct State {
+    pub x: isize,
+    pub depth: isize,
+    pub aim: isize,
+}
+
+/// How the submarine interprets commands.
+pub trait NavigationModel {
+    fn apply(&self, state: State, command: Command) -> State;
+
+    /// The state after following all `commands` from the starting point.
+    fn navigate(&self, commands: &[Command]) -> State {
+        commands.iter().fold(State::default(), |state, &command| self.apply(state, command))
+    }
+
+    /// The starting point followed by the state after each of the `commands`.
+    fn trajectory(&self, commands: &[Command]) -> Vec<State> {
+        let mut states = vec![State::default()];
+        for &command in commands {
+            states.push(self.apply(states[states.len() - 1], command));
+        }
+        states
+    }
+}
+
+/// Up and down change the depth directly, as first assumed in part A.
+pub struct Plain;
+
+/// Up and down turn the submarine, which changes depth when it moves, as in part B.
+pub struct Aimed;
+
+impl Command {
+    pub fn parse(n: usize, line: &str) -> Result<Command, ParseError> {
+        let mut tokens = line.split_whitespace();
+        let direction = expect_token(n, line, tokens.next(), "a command")?;
+        let command = if direction == "reset" {
+            Command::Reset
+        } else {
+            let command = match direction {
+                "forward" => Command::Forward,
+                "back" => Command::Back,
+                "down" => Command::Down,
+                "up" => Command::Up,
+                _ => return Err(ParseError::invalid(n, line, direction, "forward, back, down, up or reset")),
+            };
+            command(parse_token(n, line, expect_token(n, line, tokens.next(), "a magnitude")?)?)
+        };
+        match tokens.next() {
+            Some(extra) => Err(ParseError::invalid(n, line, extra, "the end of the line")),
+            None => Ok(command),
+        }
+    }
+}
+
+impl NavigationModel for Plain {
+    fn apply(&self, state: State, command: Command) -> State {
+        match command {
+            Command::Forward(n) => State { x: state.x + n, ..state },
+            Command::Back(n) => State { x: state.x - n, ..state },
+            Command::Down(n) => State { depth: state.depth + n, ..state },
+            Command::Up(n) => State { depth: state.depth - n, ..state },
+            Command::Reset => State::default(),
+        }
+    }
+}
+
+impl NavigationModel for Aimed {
+    fn apply(&self, state: State, command: Command) -> State {
+        match command {
+            Command::Forward(n) => State { x: state.x + n, depth: state.depth + state.aim * n, ..state },
+            Command::Back(n) => State { x: state.x - n, depth: state.depth - state.aim * n, ..state },
+            Command::Down(n) => State { aim: state.aim + n, ..state },
+            Command::Up(n) => State { aim: state.aim - n, ..state },
+            Command::Reset => State::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{Aimed, Command, Day2, NavigationModel, Plain, State};
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn test_example() {
+        let input = Day2::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("150", Day2::part_a(&input).to_string());
+        assert_eq!("900", Day2::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_navigation_models() {
+        let commands = Day2::parse(example_lines("down 2\nforward 3\nback 1\nreset\nup 1\nforward 2")).unwrap();
+        assert_eq!(Command::Back(1), commands[2]);
+        assert_eq!(Command::Reset, commands[3]);
+        let state = |x, depth, aim| State { x, depth, aim };
+        assert_eq!(vec![state(0, 0, 0), state(0, 2, 0), state(3, 2, 0), state(2, 2, 0), state(0, 0, 0), state(0, -1, 0), state(2, -1, 0)],
+                   Plain.trajectory(&commands));
+        assert_eq!(vec![state(0, 0, 0), state(0, 0, 2), state(3, 6, 2), state(2, 4, 2), state(0, 0, 0), state(0, 0, -1), state(2, -2, -1)],
+                   Aimed.trajectory(&commands));
+        assert_eq!(state(2, -2, -1), Aimed.navigate(&commands));
+
+        assert_eq!("<input>:1:1: expected forward, back, down, up or reset, found 'left'", Day2::parse(example_lines("left 2")).unwrap_err().to_string());
+        assert_eq!("<input>:1:7: expected the end of the line, found '1'", Day2::parse(example_lines("reset 1")).unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,242 @@
+use itertools::{Itertools};
+use tracing::{debug, trace};
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::Grid;
+use crate::solution::{Output, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Image;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        let mut lines = (1..).zip(lines);
+        let (n, line) = lines.next().ok_or_else(|| ParseError::missing(1, "", "an enhancement algorithm"))?;
+        let algo = parse_pixels(n, &line, 512)?;
+        let pixels = Grid::parse_numbered(lines.skip(1), "'#' or '.'", |c| "#.".contains(c).then_some(c))
+            .map_err(|e| match e {
+                ParseError::Missing { line, .. } if line <= n + 2 => ParseError::missing(n + 2, "", "an input image"),
+                e => e,
+            })?;
+        Ok(Image::new(pixels, algo))
+    }
+
+    fn part_a(image: &Self::Input) -> Output {
+        task(image, 2).into()
+    }
+
+    fn part_b(image: &Self::Input) -> Output {
+        task(image, 50).into()
+    }
+}
+
+impl Generate for Day20 {
+    /// Side of the square input image.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut pixel = || if rng.chance(0.5) { '#' } else { '.' };
+        let mut algorithm = (0..512).map(|_| pixel()).collect::<String>();
+        // an algorithm that lights up the dark infinity has to turn it off again
+        if algorithm.starts_with('#') {
+            algorithm.replace_range(511.., ".");
+        }
+        format!("{}\n\n{}\n", algorithm, Grid::from_fn(size.max(1), size.max(1), |_| pixel()))
+    }
+}
+
+fn parse_pixels(n: usize, line: &str, count: usize) -> Result<Vec<char>, ParseError> {
+    let pixels = line.char_indices()
+        .map(|(i, c)| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(ParseError::invalid(n, line, &line[i..i + c.len_utf8()], "'#' or '.'")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match pixels.len() {
+        len if len < count => Err(ParseError::missing(n, line, &format!("{} pixels", count))),
+        len if len > count => Err(ParseError::invalid(n, line, &line[count..], &format!("{} pixels", count))),
+        _ => Ok(pixels),
+    }
+}
+
+fn task(image: &Image, enhancement_count: usize) -> usize {
+    let mut image = image.clone();
+    image.print();
+    for i in 0..enhancement_count {
+        debug!("enhancement {}", i);
+        image = image.enhance();
+    }
+    image.print().count_lit_pixels()
+}
+
+
+type Coords = (isize, isize);
+
+#[derive(Clone)]
+pub struct Image {
+    origin: Coords,
+    pixels: Grid<char>,
+    algo: Vec<char>,
+    is_infinity_lit: bool
+}
+
+impl Image {
+    pub fn new(pixels: Grid<char>, algo: Vec<char>) -> Self {
+        Image {
+            pixels,
+            algo,
+            origin: (0, 0),
+            is_infinity_lit: false
+        }
+    }
+
+    fn pixel_at(&self, coords: Coords) -> &char {
+        let (x, y) = (coords.0 - self.origin.0, coords.1 - self.origin.1);
+        usize::try_from(x).ok().zip(usize::try_from(y).ok())
+            .and_then(|p| self.pixels.get(p))
+            .unwrap_or_else(|| self.infinity_pixel())
+    }
+
+    fn coords_around(&self, coords: Coords) -> Vec<Coords> {
+        let (x, y) = coords;
+        (y-1..y+2).flat_map(|y| (x-1..x+2).map(|x|(x, y)).collect_vec()).collect()
+    }
+
+    fn pixels_at(&self, coords: &[Coords]) -> Vec<char> {
+        coords.iter().map(|&coords| *self.pixel_at(coords)).collect()
+    }
+
+    fn algo_index_from(&self, coord: Coords) -> usize {
+        let relevant_coords = self.coords_around(coord);
+
+        let relevant_pixels = self.pixels_at(&relevant_coords);
+        let binary_string = relevant_pixels.iter()
+            .map(|p| match p { '#' => '1', _  => '0' })
+            .join("");
+        usize::from_str_radix(&binary_string, 2).unwrap()
+    }
+
+    fn output_pixel_at(&self, coord: Coords) -> &char {
+        let index = self.algo_index_from(coord);
+        &self.algo[index]
+    }
+
+    pub fn enhance(&self) -> Self {
+        let origin = (self.origin.0 - 1, self.origin.1 - 1);
+        let pixels = Grid::from_fn(self.pixels.width() + 2, self.pixels.height() + 2, |(x, y)| {
+            *self.output_pixel_at((origin.0 + x as isize, origin.1 + y as isize))
+        });
+
+        let is_infinity_lit = (!self.is_infinity_lit && self.flips_infinity_on())
+            || (self.is_infinity_lit && !self.flips_infinity_off());
+
+        Self {
+            pixels,
+            algo: self.algo.clone(),
+            origin,
+            is_infinity_lit,
+        }
+    }
+
+    fn print(&self) -> &Self {
+        trace!("\n{}", self.pixels);
+        self
+    }
+
+    pub fn count_lit_pixels(&self) -> usize {
+        self.pixels.values()
+            .filter(|p| p == &&'#')
+            .count()
+    }
+
+    fn flips_infinity_on(&self) -> bool {
+        self.algo[0] == '#'
+    }
+
+    fn flips_infinity_off(&self) -> bool {
+        self.algo[self.algo.len()-1] == '.'
+    }
+
+    fn infinity_pixel(&self) -> &char {
+        if self.is_infinity_lit {
+            &'#'
+        } else {
+            &'.'
+        }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use crate::grid::Grid;
+    use super::Image;
+
+    #[test]
+    fn test_example_0() {
+        let algo = [
+            "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##",
+             "#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###",
+             ".######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.",
+             ".#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....",
+             ".#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..",
+             "...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....",
+             "..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#"
+        ].concat();
+
+        assert_eq!(512, algo.chars().count());
+
+        let input_image = Grid::from_cells(5, "#..#.\
+                                #....\
+                                ##..#\
+                                ..#..\
+                                ..###".chars().collect());
+
+        let image = Image::new(
+            input_image.clone(),
+            algo.chars().collect()
+        );
+
+        assert_eq!(&'#', image.pixel_at((0, 0)));
+        assert_eq!(&'.', image.pixel_at((4, 0)));
+        assert_eq!(&'.', image.pixel_at((0, 4)));
+        assert_eq!(&'#', image.pixel_at((4, 4)));
+        assert_eq!(5, image.pixels.height());
+
+        let relevant_pixels = vec![(4, 9), (5, 9), (6, 9), (4, 10), (5, 10), (6, 10), (4, 11), (5, 11), (6, 11)];
+        assert_eq!(relevant_pixels, image.coords_around((5, 10)));
+
+        let relevant_pixels = vec![(1, 1), (2, 1), (3, 1), (1, 2), (2, 2), (3, 2), (1, 3), (2, 3), (3, 3)];
+        assert_eq!(relevant_pixels, image.coords_around((2, 2)));
+
+        assert_eq!("...#...#.", image.pixels_at(&relevant_pixels).iter().join(""));
+        assert_eq!(34, image.algo_index_from((2,2)));
+        assert_eq!(&'#', image.output_pixel_at((2,2)));
+
+        println!("-----------enhance 0------------");
+        image.print();
+        println!("-----------enhance 1------------");
+        image.enhance().print();
+        println!("-----------enhance 2------------");
+        image.enhance().enhance().print();
+
+        assert_eq!(35, image.enhance().enhance().count_lit_pixels());
+
+        let mut image = Image::new(
+            input_image,
+            algo.chars().collect()
+        );
+
+        for i in 0..50 {
+            println!("-----------enhance  {} ------------", i);
+            image = image.enhance();
+            image.print();
+        }
+        assert_eq!(3351, image.print().count_lit_pixels());
+
+    }
+
+}
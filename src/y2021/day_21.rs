@@ -1,37 +1,64 @@
 use std::collections::HashMap;
-use std::usize;
 use itertools::{Itertools};
+use crate::error::{parse_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (usize, usize);
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        let positions = (1..).zip(lines).take(2)
+            .map(|(n, line)| parse_starting_position(n, &line))
+            .collect::<Result<Vec<_>, _>>()?;
+        match positions[..] {
+            [a, b] => Ok((a, b)),
+            _ => Err(ParseError::missing(positions.len() + 1, "", &format!("the starting position of player {}", positions.len() + 1))),
+        }
+    }
 
-mod lib;
+    fn part_a(starting_positions: &Self::Input) -> Output {
+        let mut game = Game::new_with( vec![
+                Player::new(starting_positions.0),
+                Player::new(starting_positions.1)
+            ],
+           1000,
+           deterministic_die(100)
+        );
+        game.run_until_win().into()
+    }
 
-fn main() {
-    let task_a = task_a();
-    let task_b = task_b();
-    assert_eq!(598416, task_a);
-    assert_eq!(27674034218179, task_b);
-    println!("task_a: {}, task_b: {}", task_a, task_b);
+    fn part_b(starting_positions: &Self::Input) -> Output {
+        let game = Game::new_with( vec![
+                Player::new(starting_positions.0),
+                Player::new(starting_positions.1)
+            ],
+           21,
+           deterministic_die(3)
+        );
+        (*game.count_universal_wins(&mut HashMap::new()).iter().max().unwrap()).into()
+    }
 }
 
-fn task_a() -> usize {
-    let mut game = Game::new_with( vec![
-            Player::new(1),
-            Player::new(2)
-        ],
-       1000,
-       deterministic_die(100)
-    );
-    game.run_until_win()
+impl Generate for Day21 {
+    /// Number of players. There are always two, so the size is ignored.
+    const SIZE: usize = 2;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        (1..=2).map(|player| format!("Player {} starting position: {}\n", player, rng.range(1..=10))).collect()
+    }
 }
 
-fn task_b() -> usize {
-    let game = Game::new_with( vec![
-            Player::new(1),
-            Player::new(2)
-        ],
-       21,
-       deterministic_die(3)
-    );
-    *game.count_universal_wins(&mut HashMap::new()).iter().max().unwrap()
+fn parse_starting_position(n: usize, line: &str) -> Result<usize, ParseError> {
+    let prefix = format!("Player {} starting position: ", n);
+    let position = line.strip_prefix(&prefix)
+        .ok_or_else(|| ParseError::invalid(n, line, line, &format!("'{}<1-10>'", prefix)))?;
+    match parse_token(n, line, position)? {
+        p @ 1..=10 => Ok(p),
+        _ => Err(ParseError::invalid(n, line, position, "a position between 1 and 10")),
+    }
 }
 
 #[derive(Clone)]
@@ -155,7 +182,7 @@ impl Game {
     }
 
     fn cache_key(&self) -> String {
-        vec![self.turns.state,
+        [self.turns.state,
              self.die.state,
              self.players[0].spaces.state,
              self.players[1].spaces.state,
@@ -177,7 +204,20 @@ fn deterministic_die(sides: usize) -> CyclingIterator {
 mod tests {
     use std::collections::HashMap;
     use itertools::Itertools;
-    use crate::{deterministic_die, Game, Player};
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{deterministic_die, Day21, Game, Player};
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8";
+
+    #[test]
+    fn test_solution_example() {
+        let input = Day21::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("739785", Day21::part_a(&input).to_string());
+        assert_eq!("444356092776315", Day21::part_b(&input).to_string());
+    }
 
     #[test]
     fn test_example_0() {
@@ -190,9 +230,9 @@ mod tests {
         assert_eq!(2, r);
 
         let mut p = Player::new(4);
-        let s = p.move_forward(3);
+        p.move_forward(3);
         assert_eq!(p.spaces.state, 7);
-        let s = p.move_forward(3);
+        p.move_forward(3);
         assert_eq!(p.spaces.state, 10);
 
         let players = vec![Player::new(4), Player::new(8)];
@@ -206,10 +246,9 @@ mod tests {
 
 
         let players = vec![Player::new(4), Player::new(8)];
-        let mut game = Game::new_with(players, 21, deterministic_die(3));
+        let game = Game::new_with(players, 21, deterministic_die(3));
         println!("{:?}", game.count_universal_wins(&mut HashMap::new()));
 
     }
 
 }
-
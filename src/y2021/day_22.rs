@@ -1,54 +1,88 @@
 use std::collections::HashSet;
 use itertools::{Itertools};
-use regex::Regex;
-use crate::lib::read_lines;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::lines;
+use crate::parse::{list, range};
+use crate::solution::{Output, Solution};
 
-mod lib;
+pub struct Day22;
 
-fn main() {
-    let task_a = task_a(read_lines("input/day_22.txt"));
-    assert_eq!(580810, task_a);
-    let task_b = task_b(read_lines("input/day_22.txt"));
-    assert_eq!(1265621119006734, task_b);
-    println!("task_a: {}, task_b: {}", task_a, task_b);
-}
+impl Solution for Day22 {
+    type Input = Vec<(Action, Cuboid)>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part_a(commands: &Self::Input) -> Output {
+        let commands = commands.iter()
+            .filter(|(_, c)|
+                c.from.x <= 50 && c.to.x >= -50
+                && c.from.y <= 50 && c.to.y >= -50
+                && c.from.z <= 50 && c.to.z >= -50
+            )
+            .cloned()
+            .collect_vec();
+        let mut reactor = Reactor::new();
+        reactor.perform_all(&commands);
+        reactor.count_cubes().into()
+    }
 
-fn task_a(lines: impl Iterator<Item=String>) -> isize {
-    let commands = parse_input(lines).into_iter()
-        .filter(|(_, c)|
-            c.from.x <= 50 && c.to.x >= -50
-            && c.from.y <= 50 && c.to.y >= -50
-            && c.from.z <= 50 && c.to.z >= -50
-        )
-        .collect_vec();
-    let mut reactor = Reactor::new();
-    reactor.perform_all(&commands);
-    reactor.count_cubes()
+    fn part_b(commands: &Self::Input) -> Output {
+        let mut reactor = Reactor::new();
+        reactor.perform_all(commands);
+        reactor.count_cubes().into()
+    }
 }
 
-fn task_b(lines: impl Iterator<Item=String>) -> isize {
-    let commands = parse_input(lines);
-    let mut reactor = Reactor::new();
-    reactor.perform_all(&commands);
-    reactor.count_cubes()
+impl Generate for Day22 {
+    /// Number of reboot steps. One in 21 steps is within the initialization area, and these come
+    /// first, the others lie entirely outside of it.
+    const SIZE: usize = 420;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|step| {
+                let action = if step == 0 || rng.chance(0.7) { "on" } else { "off" };
+                let ranges = if step < (size / 21).max(1) {
+                    [(); 3].map(|_| {
+                        let (a, b) = (rng.range(-50..=50), rng.range(-50..=50));
+                        (a.min(b), a.max(b))
+                    })
+                } else {
+                    let mut ranges = [(); 3].map(|_| {
+                        let from = rng.range(-100_000..=90_000);
+                        (from, from + rng.range(0..=30_000))
+                    });
+                    let (from, length) = (rng.range(51..=90_000), rng.range(0..=30_000));
+                    ranges[rng.below(3)] = if rng.chance(0.5) { (from, from + length) } else { (-from - length, -from) };
+                    ranges
+                };
+                format!("{} x={}..{},y={}..{},z={}..{}\n", action, ranges[0].0, ranges[0].1, ranges[1].0, ranges[1].1, ranges[2].0, ranges[2].1)
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Hash, Clone, PartialEq, Eq)]
 struct Cube {x:isize, y:isize, z:isize}
 
 #[derive(Debug, Hash, Clone, PartialEq, Eq)]
-struct Cuboid {
+pub struct Cuboid {
     from: Cube,
     to: Cube,
 }
 
-struct Reactor {
+#[derive(Default)]
+pub struct Reactor {
     active_cuboids: HashSet<Cuboid>,
 }
 
 impl Cuboid {
 
-    fn from_ranges(x:(isize, isize), y: (isize, isize), z: (isize, isize)) -> Self {
+    pub fn from_ranges(x:(isize, isize), y: (isize, isize), z: (isize, isize)) -> Self {
         let from = Cube{x: x.0, y: y.0, z: z.0};
         let to = Cube{x: x.1, y: y.1, z: z.1};
         Self {from, to}
@@ -94,15 +128,15 @@ impl Cuboid {
             && self.from.z <= other.from.z && self.to.z >= other.to.z
     }
 
-    fn volume(&self) -> isize {
+    pub fn volume(&self) -> isize {
         self.len_x() * self.len_y() * self.len_z()
     }
 
     fn split_around(&self, intersection: &Cuboid) -> HashSet<Cuboid> {
         let i = intersection;
-        let x_cuts = vec![(self.from.x, i.from.x-1), (i.from.x, i.to.x), (i.to.x+1, self.to.x)];
-        let y_cuts = vec![(self.from.y, i.from.y-1), (i.from.y, i.to.y), (i.to.y+1, self.to.y)];
-        let z_cuts = vec![(self.from.z, i.from.z-1), (i.from.z, i.to.z), (i.to.z+1, self.to.z)];
+        let x_cuts = [(self.from.x, i.from.x-1), (i.from.x, i.to.x), (i.to.x+1, self.to.x)];
+        let y_cuts = [(self.from.y, i.from.y-1), (i.from.y, i.to.y), (i.to.y+1, self.to.y)];
+        let z_cuts = [(self.from.z, i.from.z-1), (i.from.z, i.to.z), (i.to.z+1, self.to.z)];
 
         x_cuts.iter().flat_map(|x| {
             y_cuts.iter().flat_map( |y| {
@@ -118,19 +152,17 @@ impl Cuboid {
 }
 
 #[derive(Debug, Hash, Clone, PartialEq, Eq)]
-enum Action {
+pub enum Action {
     On, Off
 }
 
 impl Reactor {
 
-    fn new() -> Self {
-        Self {
-            active_cuboids: Default::default()
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    fn perform(&mut self, action: &Action, cuboid: &Cuboid ) {
+    pub fn perform(&mut self, action: &Action, cuboid: &Cuboid ) {
         let intersections = self.active_cuboids.iter()
             .cloned()
             .filter_map(|c|cuboid.intersection(&c).map(|i|(c,i)))
@@ -143,7 +175,7 @@ impl Reactor {
                 let mut parts = intersecting_cuboid.split_around(&intersection);
                 parts.remove(&intersection);
                 self.active_cuboids.remove(&intersecting_cuboid);
-                self.active_cuboids.extend(parts.into_iter());
+                self.active_cuboids.extend(parts);
             }
         }
         if action == &Action::On {
@@ -151,39 +183,61 @@ impl Reactor {
         }
     }
 
-    fn perform_all(&mut self, actions: &[(Action, Cuboid)] ) {
+    pub fn perform_all(&mut self, actions: &[(Action, Cuboid)] ) {
        actions.iter().for_each(|(a, c)| {
            self.perform(a, c);
        })
     }
 
-    fn count_cubes(&self) -> isize {
+    pub fn count_cubes(&self) -> isize {
         self.active_cuboids.iter().map(|c|c.volume()).sum()
     }
 
 
 }
 
-fn parse_input(lines: impl Iterator<Item=String>) -> Vec<(Action, Cuboid)> {
-
-    let re = Regex::new(r"^(.+?) x=(.+?)\.\.(.+?),y=(.+?)\.\.(.+?),z=(.+?)\.\.(.+?)$").unwrap();
-
-    lines.map(|line|{
-        let cap = re.captures_iter(line.as_str()).next().unwrap();
-        let mut i = cap.iter().skip(1);
-        let command = i.next().unwrap().unwrap().as_str();
-        let ranges = i.map(|v|v.unwrap().as_str().parse::<isize>().unwrap()).tuples().collect_vec();
-        let action = if command == "on" {Action::On} else {Action::Off};
-        (action, Cuboid::from_ranges(ranges[0], ranges[1], ranges[2]))
-    }).collect_vec()
+fn parse_input(text: &str) -> Result<Vec<(Action, Cuboid)>, ParseError> {
+    lines(text).map(|line| {
+        let malformed = || line.invalid(line.text, "'on|off x=..,y=..,z=..'");
+        let (command, ranges) = line.text.split_once(' ').ok_or_else(malformed)?;
+        let action = match command {
+            "on" => Action::On,
+            "off" => Action::Off,
+            _ => return Err(line.invalid(command, "on or off")),
+        };
+        let mut axes = ["x", "y", "z"].into_iter();
+        let ranges = list(ranges, ",", |r| range(line, r, axes.next().ok_or_else(|| line.invalid(r, "3 ranges"))?))?;
+        match ranges[..] {
+            [x, y, z] => Ok((action, Cuboid::from_ranges(x, y, z))),
+            _ => Err(malformed()),
+        }
+    }).collect()
 }
 
 
 #[cfg(test)]
 mod tests {
-    use itertools::Itertools;
-    use crate::{Cube, Cuboid, Reactor};
-    use crate::Action::{Off, On};
+    use std::collections::HashSet;
+    use itertools::{iproduct, Itertools};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use crate::input::{example_lines, parse_example};
+    use crate::solution::{Output, Solution};
+    use super::{Cube, Cuboid, Day22, Reactor};
+    use super::Action::{Off, On};
+
+    const EXAMPLE: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    #[test]
+    fn test_solution_example() {
+        let input = Day22::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("39", Day22::part_a(&input).to_string());
+        assert_eq!("39", Day22::part_b(&input).to_string());
+    }
 
     #[test]
     fn test_example_0() {
@@ -249,5 +303,28 @@ mod tests {
         assert_eq!(216, a.volume());
         assert_eq!(216, parts.iter().map(|p|p.volume()).sum::<isize>());
     }
-}
 
+    /// Keeps the cubes that are on in a set.
+    fn switch_cubes(steps: &[(bool, [(isize, isize); 3])]) -> usize {
+        let mut cubes = HashSet::new();
+        for (on, [x, y, z]) in steps {
+            for cube in iproduct!(x.0..=x.1, y.0..=y.1, z.0..=z.1) {
+                if *on { cubes.insert(cube); } else { cubes.remove(&cube); }
+            }
+        }
+        cubes.len()
+    }
+
+    fn range() -> impl Strategy<Value=(isize, isize)> {
+        (-4..=4isize, -4..=4isize).prop_map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_cube_switching(steps in vec((any::<bool>(), [range(), range(), range()]), 1..8)) {
+            let input = parse_example::<Day22>(steps.iter()
+                .map(|(on, [x, y, z])| format!("{} x={}..{},y={}..{},z={}..{}", if *on { "on" } else { "off" }, x.0, x.1, y.0, y.1, z.0, z.1)));
+            prop_assert_eq!(Output::from(switch_cubes(&steps)), Day22::part_b(&input));
+        }
+    }
+}
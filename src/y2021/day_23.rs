@@ -1,47 +1,64 @@
-use std::array::from_ref;
-use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
-use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::{Index, IndexMut};
-use std::ptr;
-use std::ptr::hash;
-use std::rc::Rc;
-use std::slice::SliceIndex;
+use std::ops::Index;
 use itertools::{Itertools};
-use pathfinding::prelude::{astar, bfs};
-use rayon::iter::Empty;
-use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
-use regex::Regex;
-use crate::Cell::*;
-use crate::CellType::{Doorstep, Floor, Room};
-use crate::lib::read_lines;
-use crate::Occupant::{Amphipod, Wall};
-
-mod lib;
-
-fn main() {
-    let task_a = task(read_lines("input/day_23_1.txt"), 2);
-    assert_eq!(15322, task_a);
-    println!("result-a: {}", task_a);
-
-    let task_b = task(read_lines("input/day_23_2.txt"), 4);
-    assert_eq!(56324, task_b);
-    println!("result-b: {}", task_b);
+use pathfinding::prelude::astar;
+use self::Cell::*;
+use self::CellType::{Doorstep, Floor, Room};
+use self::Occupant::{Amphipod, Wall};
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::{Grid, Position};
+use crate::solution::{Output, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        let lines = lines.collect_vec();
+        for (n, line) in (1..).zip(&lines) {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !" #.ABCD".contains(*c)) {
+                return Err(ParseError::invalid(n, line, &line[i..i + c.len_utf8()], "a wall, floor or amphipod"));
+            }
+        }
+        if lines.len() < 5 {
+            return Err(ParseError::missing(lines.len() + 1, "", "a burrow of 5 lines"));
+        }
+        Ok(lines)
+    }
+
+    fn part_a(lines: &Self::Input) -> Output {
+        task(lines.iter().cloned(), 2).into()
+    }
+
+    fn part_b(lines: &Self::Input) -> Output {
+        let mut lines = lines.clone();
+        lines.splice(3..3, ["  #D#C#B#A#".to_string(), "  #D#B#A#C#".to_string()]);
+        task(lines.into_iter(), 4).into()
+    }
+}
+
+impl Generate for Day23 {
+    /// Number of amphipods. The burrow always has two of each kind, so the size is ignored.
+    const SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut amphipods = "AABBCCDD".chars().collect_vec();
+        rng.shuffle(&mut amphipods);
+        let (top, bottom) = amphipods.split_at(4);
+        format!("#############\n#...........#\n###{}###\n  #{}#\n  #########\n", top.iter().join("#"), bottom.iter().join("#"))
+    }
 }
 
 fn task(lines: impl Iterator<Item=String>, room_height: usize) -> usize {
     let energy_map = HashMap::from([
         ('A', 1),('B', 10),('C', 100),('D', 1000),
     ]);
-    let door_steps = vec![
-        Position(3,1),
-        Position(5,1),
-        Position(7,1),
-        Position(9,1)
-    ];
+    let door_steps = vec![(3, 1), (5, 1), (7, 1), (9, 1)];
     let start = Map::from(lines, energy_map, door_steps, room_height);
 
     let result = astar(&start, |m|
@@ -78,11 +95,6 @@ enum Cell {
     Occupied(CellType, Occupant),
 }
 
-impl Default for Cell {
-    fn default() -> Self {
-        Self::Empty(CellType::Floor)
-    }
-}
 
 
 // --------------- Map --------------------
@@ -112,7 +124,7 @@ impl PartialEq for Map {
 
 impl Display for Map {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s = self.grid.rows.iter().map(|row| row.iter().map(|c| match c {
+        let s = self.grid.rows().map(|row| row.iter().map(|c| match c {
             Empty(_) => ' ',
             Occupied(Floor, Wall) => '▒',
             Occupied(_, Amphipod(id, _)) => *id,
@@ -136,8 +148,10 @@ impl Map {
             .map(|room|(*room, RefCell::new(0))));
 
 
-        let rows = lines
-            .map(|l| l.chars().collect_vec())
+        let lines = lines.collect_vec();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let cells = lines.into_iter()
+            .map(|l| format!("{:<width$}", l).chars().collect_vec())
             .map(|chars| {
                 let mut color_stack = energy_map.keys().sorted().collect_vec();
                 color_stack.reverse();
@@ -156,19 +170,19 @@ impl Map {
                         )
                     }
                 }).collect_vec()
-            }).collect_vec();
+            }).concat();
 
         let room_cell_count = energy_map.len() * room_height;
 
         let mut map = Self {
-            grid: Grid::from(rows),
+            grid: Grid::from_cells(width, cells),
             wcc_map,
             energy_map,
             room_cell_count
         };
 
         door_steps.iter().for_each(|p| {
-            map.grid.replace(p, Empty(Doorstep));
+            map.grid[*p] = Empty(Doorstep);
         });
 
         map
@@ -182,11 +196,11 @@ impl Map {
     }
 
     pub fn visit_amphipods(&self) -> impl Iterator<Item=(&Cell, Position)> {
-        self.grid.visit_cells_with_position().filter(|(c, _)| matches!(c, Occupied(_, Amphipod(_, _))))
+        self.grid.iter().map(|(p, c)| (c, p)).filter(|(c, _)| matches!(c, Occupied(_, Amphipod(_, _))))
     }
 
     fn recurse_reachable_from(&self, p: &Position, steps: Steps, visited: &mut HashSet<Position>) -> Vec<(&Cell, Position, Steps)> {
-        let neighbours = p.neighbours().into_iter()
+        let neighbours = self.grid.neighbours_4(*p)
             .filter(|n| matches!(self[n], Cell::Empty(_)))
             .filter(|n| !visited.contains(n))
             .collect_vec();
@@ -225,7 +239,7 @@ impl Map {
             Occupied(Floor, _) => Empty(Floor),
             _ => panic!("unexpected case {:?}", self[p])
         };
-        self.grid.replace(p, replacement)
+        std::mem::replace(&mut self.grid[*p], replacement)
     }
 
     fn occupy_field(&mut self, p: &Position, amphi_color: AmphiColor) -> Cell {
@@ -234,7 +248,7 @@ impl Map {
             Empty(Floor) => Occupied(Floor, Amphipod(amphi_color, true)),
             _ => panic!("unexpected case {:?}", self[p])
         };
-        self.grid.replace(p, replacement)
+        std::mem::replace(&mut self.grid[*p], replacement)
     }
 
     fn move_amphipod(&mut self, from: &Position, to: &Position) {
@@ -286,7 +300,7 @@ impl Map {
 
     fn heuristic(&self) -> usize {
         //todo: optimize by adding correct_cell_count to Self
-        let amphipods_in_correct_cell_count = self.visit_amphipods().filter(|(c, p)|
+        let amphipods_in_correct_cell_count = self.visit_amphipods().filter(|(c, _)|
             matches!(c, Occupied(Room(r), Amphipod(a, _)) if a == r)
         ).count();
 
@@ -302,95 +316,28 @@ impl Index<&Position> for Map {
     type Output = Cell;
 
     fn index(&self, index: &Position) -> &Self::Output {
-        &self.grid[index]
-    }
-}
-
-
-// --------------- GRID --------------------
-
-#[derive(Debug, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
-struct Position(usize, usize);
-
-impl Position {
-    fn neighbours(&self) -> Vec<Self> {
-        vec![
-            Position(self.0 + 1, self.1),
-            Position(self.0 - 1, self.1),
-            Position(self.0, self.1 + 1),
-            Position(self.0, self.1 - 1),
-        ]
-    }
-}
-
-#[derive(Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
-struct Grid<T> where T: Default + Clone {
-    rows: Vec<Vec<T>>,
-}
-
-impl<T> Grid<T> where T: Default + Clone {
-    fn new(width: usize, height: usize) -> Self {
-        Grid {
-            rows: vec![vec![T::default(); width]; height]
-        }
-    }
-
-    fn swap(&mut self, a: &Position, b: &Position) {
-        unsafe {
-            let pa: *mut T = &mut self[a];
-            let pb: *mut T = &mut self[b];
-            ptr::swap(pa, pb);
-        }
-    }
-
-    fn replace(&mut self, at: &Position, mut with: T) -> T {
-        unsafe {
-            let pa: *mut T = &mut self[at];
-            let pb: *mut T = &mut with;
-            ptr::swap(pa, pb);
-        }
-        with
-    }
-
-    fn visit_rows(&self) -> impl Iterator<Item=&Vec<T>> {
-        self.rows.iter()
-    }
-
-    fn visit_cells(&self) -> impl Iterator<Item=&T> {
-        self.visit_rows().flat_map(|r| r.iter())
-    }
-
-    fn visit_cells_with_position(&self) -> impl Iterator<Item=(&T, Position)> {
-        self.visit_rows()
-            .enumerate()
-            .flat_map(|(row_index, row)| row.iter()
-                .enumerate()
-                .map(move |(col_index, cell)| {
-                    (cell, Position(col_index, row_index))
-                }))
-    }
-}
-
-impl<'a, T> Index<&'a Position> for Grid<T>
-    where T: Default + Clone {
-    type Output = T;
-
-    fn index(&self, index: &'a Position) -> &Self::Output {
-        &self.rows[index.1][index.0]
+        &self.grid[*index]
     }
 }
 
-impl <T>From<Vec<Vec<T>>> for Grid<T> where T: Default + Clone {
-    fn from(rows: Vec<Vec<T>>) -> Self {
-        Grid { rows }
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day23;
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    #[ignore = "takes minutes and gigabytes of memory, run with --release -- --ignored"]
+    fn test_example() {
+        let input = Day23::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("12521", Day23::part_a(&input).to_string());
+        assert_eq!("44169", Day23::part_b(&input).to_string());
     }
 }
-
-impl<'a, T> IndexMut<&'a Position> for Grid<T>
-    where T: Default + Clone {
-    fn index_mut(&mut self, index: &'a Position) -> &mut Self::Output {
-        &mut self.rows[index.1][index.0]
-    }
-}
-
-
@@ -0,0 +1,471 @@
+use std::fmt::{Debug, Display, Formatter};
+use itertools::{Itertools};
+use self::Instruction::*;
+use self::Value::Number;
+use crate::error::{expect_token, parse_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        parse_input(lines)
+    }
+
+    fn part_a(instructions: &Self::Input) -> Output {
+        task(instructions, true).into()
+    }
+
+    fn part_b(instructions: &Self::Input) -> Output {
+        task(instructions, false).into()
+    }
+}
+
+impl Generate for Day24 {
+    /// Number of digits of the model number, rounded down to an even number of at most 18.
+    const SIZE: usize = 14;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // every block that pops a digit off z pairs up with an earlier one that pushed a digit,
+        // and the two digits must differ by an offset for z to end up 0
+        let digits = size.clamp(2, 18) / 2 * 2;
+        let mut params: Vec<BlockParams> = vec![];
+        let mut pushed = vec![];
+        for i in 0..digits {
+            if pushed.len() + i < digits && (pushed.is_empty() || rng.chance(0.5)) {
+                pushed.push(i);
+                params.push((1, rng.range(10..=16) as isize, rng.range(1..=16) as isize));
+            } else {
+                let j = pushed.pop().expect("an earlier push");
+                params.push((26, rng.range(-8..=8) as isize - params[j].2, rng.range(1..=16) as isize));
+            }
+        }
+        monad(&params)
+    }
+}
+
+/// The program of one block per digit with the given parameters, as in the puzzle inputs.
+fn monad(params: &[BlockParams]) -> String {
+    params.iter()
+        .map(|(c, a, b)| format!("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n", c, a, b))
+        .collect()
+}
+
+fn task(instructions: &[Instruction], largest: bool) -> isize {
+    let model_number = find_model_number(&block_params(instructions), largest);
+    let mut alu = ALU::new();
+    alu.run_program(&model_number.to_string(), instructions);
+    assert_eq!(0, *alu.var(&Pointer::Z), "model number {} rejected", model_number);
+    model_number
+}
+
+
+fn parse_input(lines: impl Iterator<Item=String>) -> Result<Vec<Instruction>, ParseError> {
+    (1..).zip(lines).map(|(n, line)| {
+        let mut parts = line.split_whitespace();
+        let op = expect_token(n, &line, parts.next(), "an instruction")?;
+        if !["inp", "add", "mul", "div", "mod", "eql"].contains(&op) {
+            return Err(ParseError::invalid(n, &line, op, "inp, add, mul, div, mod or eql"));
+        }
+        let a = parse_var(n, &line, expect_token(n, &line, parts.next(), "a variable")?)?;
+        if op == "inp" {
+            return Ok(Instruction::Inp(a));
+        }
+        let b = parse_value(n, &line, expect_token(n, &line, parts.next(), "a variable or number")?)?;
+        Ok(match op {
+            "add" => Instruction::Add(a, b),
+            "mul" => Instruction::Mul(a, b),
+            "div" => Instruction::Div(a, b),
+            "mod" => Instruction::Mod(a, b),
+            _ => Instruction::Eql(a, b),
+        })
+    })
+        .collect()
+}
+
+fn parse_var(n: usize, line: &str, p: &str) -> Result<Pointer, ParseError> {
+    match p {
+        "w" => Ok(Pointer::W),
+        "x" => Ok(Pointer::X),
+        "y" => Ok(Pointer::Y),
+        "z" => Ok(Pointer::Z),
+        _ => Err(ParseError::invalid(n, line, p, "one of w, x, y, z"))
+    }
+}
+
+fn parse_value(n: usize, line: &str, p: &str) -> Result<Value, ParseError> {
+    match p {
+        "w" | "x" | "y" | "z" => Ok(Value::Var(parse_var(n, line, p)?)),
+        _ => Ok(Value::Number(parse_token(n, line, p)?))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Number(isize),
+    Var(Pointer),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Var(p) => write!(f, "{}", p)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Pointer {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Pointer {
+    fn index(&self) -> usize {
+        match self {
+            Pointer::W => 0,
+            Pointer::X => 1,
+            Pointer::Y => 2,
+            Pointer::Z => 3,
+        }
+    }
+}
+
+impl Display for Pointer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Pointer::W => "w",
+            Pointer::X => "x",
+            Pointer::Y => "y",
+            Pointer::Z => "z"
+        })
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    Inp(Pointer),
+    Add(Pointer, Value),
+    Mul(Pointer, Value),
+    Div(Pointer, Value),
+    Mod(Pointer, Value),
+    Eql(Pointer, Value),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Inp(a) => write!(f, "{}=input", a),
+            Instruction::Add(a, b) => write!(f, "{} + {}", a, b),
+            Instruction::Mul(a, b) => write!(f, "{} * {}", a, b),
+            Instruction::Div(a, b) => write!(f, "{} / {}", a, b),
+            Instruction::Mod(a, b) => write!(f, "{} % {}", a, b),
+            Instruction::Eql(a, b) => write!(f, "{} ≓ {}", a, b),
+        }
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Default)]
+pub struct ALU {
+    input: Vec<isize>,
+    register: [isize; 4],
+}
+
+impl ALU {
+    pub fn new() -> ALU {
+        ALU::default()
+    }
+
+    pub fn run_program(&mut self, input: &str, instructions: &[Instruction]) {
+        self.input = input.chars().map(|c| c.to_digit(10).unwrap() as isize).rev().collect_vec();
+        instructions.iter().for_each(|i| self.execute(i));
+    }
+
+
+    pub fn execute(&mut self, i: &Instruction) {
+        //println!("{:?}", i);
+        match i {
+            Instruction::Inp(a) => self.register[a.index()] = self.input.pop().unwrap(),
+            Instruction::Add(a, b) => *self.var_mut(a) += self.deref(b),
+            Instruction::Mul(a, b) => *self.var_mut(a) *= self.deref(b),
+            Instruction::Div(a, b) => {
+                debug_assert!(self.deref(b) != 0);
+                *self.var_mut(a) /= self.deref(b)
+            }
+            Instruction::Mod(a, b) => {
+                debug_assert!(*self.var_mut(a) >= 0 && self.deref(b) > 0);
+                *self.var_mut(a) %= self.deref(b)
+            }
+            Instruction::Eql(a, b) => *self.var_mut(a) = self.eql(a, b),
+        }
+        //println!("{:?}", self.register);
+    }
+
+    fn var_mut(&mut self, p: &Pointer) -> &mut isize {
+        &mut self.register[p.index()]
+    }
+
+    pub fn var(&self, p: &Pointer) -> &isize {
+        &self.register[p.index()]
+    }
+
+    fn deref(&self, p: &Value) -> isize {
+        match p {
+            Value::Number(n) => *n,
+            Value::Var(p) => *self.var(p)
+        }
+    }
+
+    fn eql(&self, a: &Pointer, b: &Value) -> isize {
+        if self.var(a) == &self.deref(b) { 1 } else { 0 }
+    }
+}
+
+
+type BlockParams = (isize, isize, isize);
+
+fn block_params(instructions: &[Instruction]) -> Vec<BlockParams> {
+    instructions.chunks(18)
+        .map(|block| match (&block[4], &block[5], &block[15]) {
+            (Div(Pointer::Z, Number(c)), Add(Pointer::X, Number(a)), Add(Pointer::Y, Number(b))) => (*c, *a, *b),
+            _ => panic!("unexpected block {:?}", block)
+        })
+        .collect()
+}
+
+fn find_model_number(params: &[BlockParams], largest: bool) -> isize {
+    let mut digits = vec![0; params.len()];
+    let mut pushed = vec![];
+    for (i, (c, a, b)) in params.iter().enumerate() {
+        if *c == 1 {
+            pushed.push((i, *b));
+        } else {
+            let (j, b) = pushed.pop().expect("unbalanced program");
+            let offset = b + a;
+            digits[j] = if largest { 9.min(9 - offset) } else { 1.max(1 - offset) };
+            digits[i] = digits[j] + offset;
+        }
+    }
+    digits.iter().fold(0, |number, digit| number * 10 + digit)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use crate::generator::{Generate, Rng};
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use crate::store::{InputStore, Key};
+    use super::{ALU, block_params, BlockParams, Day24, find_model_number, Instruction, monad, parse_input, Pointer};
+    use super::Instruction::*;
+    use super::Pointer::*;
+    use super::Value::{Number, Var};
+
+    static PARAMS: [BlockParams; 14] = [
+        (1, 12, 9),
+        (1, 12, 4),
+        (1, 12, 2),
+        (26, -9, 5),
+        (26, -9, 1),
+        (1, 14, 6),
+        (1, 14, 11),
+        (26, -10, 15),
+        (1, 15, 7),
+        (26, -2, 12),
+        (1, 11, 15),
+        (26, -15, 9),
+        (26, -9, 12),
+        (26, -3, 12)
+    ];
+
+    fn sub(z: isize, a: isize, i: isize) -> isize {
+        if ((z % 26) + a) != i { 1 } else { 0 }
+    }
+
+    fn calc_level(z: isize, i: isize, level: isize) -> isize {
+        let (c, a, b) = PARAMS[level as usize];
+        ((z / c) * ((25 * sub(z, a, i)) + 1)) + ((i + b) * sub(z, a, i))
+    }
+
+    fn print_term(instructions: &[Instruction]) {
+        instructions.iter().for_each(|i| {
+            println!("{}", i)
+        });
+    }
+
+
+    #[test]
+    fn test_negate() {
+        let instructions = vec![
+            Inp(X),
+            Mul(X, Number(-1)),
+        ];
+        let mut alu = ALU::new();
+        alu.run_program("1", &instructions);
+        assert_eq!(-1, *alu.var(&X));
+    }
+
+    #[test]
+    fn test_three_times() {
+        let instructions = vec![
+            Inp(Z),
+            Inp(X),
+            Mul(Z, Number(3)),
+            Eql(Z, Var(X)),
+        ];
+        let mut alu = ALU::new();
+        alu.run_program("13", &instructions);
+        assert_eq!(1, *alu.var(&Z));
+        alu.run_program("14", &instructions);
+        assert_eq!(0, *alu.var(&Z));
+    }
+
+    #[test]
+    fn test_binary() {
+        let instructions = vec![
+            Inp(W),
+            Add(Z, Var(W)),
+            Mod(Z, Number(2)),
+            Div(W, Number(2)),
+            Add(Y, Var(W)),
+            Mod(Y, Number(2)),
+            Div(W, Number(2)),
+            Add(X, Var(W)),
+            Mod(X, Number(2)),
+            Div(W, Number(2)),
+            Mod(W, Number(2)),
+        ];
+        let mut alu = ALU::new();
+        alu.run_program("0", &instructions);
+        assert_eq!(0, *alu.var(&W));
+        assert_eq!(0, *alu.var(&X));
+        assert_eq!(0, *alu.var(&Y));
+        assert_eq!(0, *alu.var(&Z));
+        alu.run_program("9", &instructions);
+        assert_eq!(1, *alu.var(&W));
+        assert_eq!(0, *alu.var(&X));
+        assert_eq!(0, *alu.var(&Y));
+        assert_eq!(1, *alu.var(&Z));
+    }
+
+    #[test]
+    fn test_model_number() {
+        let instructions = Day24::parse(example_lines(&monad(&PARAMS))).unwrap();
+        assert_eq!(instructions.len(), 252);
+        assert_eq!(PARAMS.to_vec(), block_params(&instructions));
+        assert_eq!(39924989499969, find_model_number(&PARAMS, true));
+        assert_eq!(16811412161117, find_model_number(&PARAMS, false));
+        assert_eq!("39924989499969", Day24::part_a(&instructions).to_string());
+        assert_eq!("16811412161117", Day24::part_b(&instructions).to_string());
+
+        let mut alu = ALU::new();
+        alu.run_program("39924989499970", &instructions);
+        assert_ne!(0, *alu.var(&Pointer::Z));
+    }
+
+    #[test]
+    fn test_generated_monad() {
+        let instructions = Day24::parse(example_lines(&Day24::generate(&mut Rng::new(24), 6))).unwrap();
+        let (largest, smallest) = (Day24::part_a(&instructions).to_string(), Day24::part_b(&instructions).to_string());
+        assert_eq!((6, 6), (largest.len(), smallest.len()));
+        assert!(smallest <= largest);
+    }
+
+    #[test]
+    #[ignore = "prints the blocks of the puzzle input side by side"]
+    fn analyze() {
+        let l = InputStore::new("input").read_lines(&Key::input(2021, 24)).unwrap().collect_vec();
+        (0..18).for_each(|i| {
+            print!("{} ", l[i].split(' ').next().unwrap());
+            (0..14).for_each(|j| {
+                print!("{:<4}", l[i + j * 18].split_whitespace().skip(1).join(""))
+            });
+            println!()
+        });
+    }
+
+    #[test]
+    #[ignore = "prints the instructions of the puzzle input"]
+    fn terms() {
+        let instructions = parse_input(InputStore::new("input").read_lines(&Key::input(2021, 24)).unwrap()).unwrap();
+
+        print_term(&instructions)
+    }
+
+    #[test]
+    #[ignore = "prints the puzzle input as a single formula"]
+    fn formula() {
+        let mut instructions = parse_input(InputStore::new("input").read_lines(&Key::input(2021, 24)).unwrap()).unwrap();
+        let mut formula = format!("{}", instructions.pop().unwrap());
+        instructions.reverse();
+        println!("start: {}", formula);
+
+        for (count, i) in instructions.iter().enumerate() {
+            let f = format!("({})", i);
+            let pointer = match &i {
+                Inp(a) => a,
+                Add(a, _) => a,
+                Mul(a, _) => a,
+                Div(a, _) => a,
+                Mod(a, _) => a,
+                Eql(a, _) => a,
+            };
+            //println!("replacing {} with {}",&format!("{}", pointer), &f );
+            formula = formula.replace(&format!("{}", pointer), &f);
+            //println!("{}", formula);
+            if count > 150 {
+                break;
+            }
+        }
+        println!("{}", formula)
+    }
+
+    #[test]
+    #[ignore = "prints z after each digit of a model number"]
+    #[allow(clippy::identity_op)]
+    fn experiment() {
+        let i = [3, 9, 9, 2, 4, 9, 8, 9, 4, 9, 9, 9, 6, 9];
+        let z = 0;
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 12)==i[0] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[0]) + 9) * (if (if ((z % 26) + 12)==i[0] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 12)==i[1] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[1]) + 4) * (if (if ((z % 26) + 12)==i[1] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 12)==i[2] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[2]) + 2) * (if (if ((z % 26) + 12)==i[2] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -9)==i[3] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[3]) + 5) * (if (if ((z % 26) + -9)==i[3] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -9)==i[4] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[4]) + 1) * (if (if ((z % 26) + -9)==i[4] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 14)==i[5] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[5]) + 6) * (if (if ((z % 26) + 14)==i[5] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 14)==i[6] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[6]) + 11) * (if (if ((z % 26) + 14)==i[6] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -10)==i[7] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[7]) + 15) * (if (if ((z % 26) + -10)==i[7] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 15)==i[8] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[8]) + 7) * (if (if ((z % 26) + 15)==i[8] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -2)==i[9] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[9]) + 12) * (if (if ((z % 26) + -2)==i[9] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 1) * ((25 * (if (if ((z % 26) + 11)==i[10] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[10]) + 15) * (if (if ((z % 26) + 11)==i[10] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -15)==i[11] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[11]) + 9) * (if (if ((z % 26) + -15)==i[11] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = ((z / 26) * ((25 * (if (if ((z % 26) + -9)==i[12] {1} else {0})==0 {1} else {0})) + 1)) + (((0 + i[12]) + 12) * (if (if ((z % 26) + -9)==i[12] {1} else {0})==0 {1} else {0}));
+        println!("{}", z);
+        let z = calc_level(z, i[13], 13);
+        println!("{}", z);
+
+        assert_eq!(z, 0);
+
+        println!("----");
+    }
+}
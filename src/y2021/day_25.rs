@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+use itertools::{Itertools};
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::Grid;
+use crate::solution::{Output, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Map;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        Map::from_lines(lines)
+    }
+
+    fn part_a(map: &Self::Input) -> Output {
+        let mut a = map.clone();
+        a.move_until_stop().into()
+    }
+
+    fn part_b(_: &Self::Input) -> Output {
+        Output::None
+    }
+}
+
+impl Generate for Day25 {
+    /// Side of the square map. Maps are drawn until their sea cucumbers stop within ten steps per
+    /// cell of the side, so generating a map takes about as long as solving it.
+    const SIZE: usize = 139;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        loop {
+            let grid = Grid::from_fn(size, size, |_| *rng.choose(&['.', '.', '>', 'v']));
+            let mut map = Map { grid: grid.clone() };
+            if (0..10 * size).any(|_| map.step() == 0) {
+                return grid.to_string() + "\n";
+            }
+        }
+    }
+}
+
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<char>,
+}
+
+impl Map {
+    fn from_lines(lines: impl Iterator<Item=String>) -> Result<Map, ParseError> {
+        let grid = Grid::parse(lines, "'.', '>' or 'v'", |c| ".>v".contains(c).then_some(c))?;
+        Ok(Map { grid })
+    }
+
+    fn visit(&self, c: &char, dir: (isize, isize)) -> Vec<((usize, usize),(usize, usize))> {
+        self.grid.iter()
+            .filter_map(|((x, y), v)| {
+                if v == c {
+                    let to = self.grid.wrap(x as isize + dir.0, y as isize + dir.1);
+                    let is_to_free = self.grid[to] == '.';
+                    if is_to_free { Some(((x,y), to)) }
+                    else { None }
+                } else {
+                    None
+                }
+            })
+            .collect_vec()
+    }
+
+    fn move_all_down(&mut self) -> usize {
+        let v = self.visit(  &'v', (0, 1));
+        let count = v.len();
+        for (from, to) in v {
+            self.grid[to] = 'v';
+            self.grid[from] = '.';
+        }
+        count
+    }
+
+    fn move_all_right(&mut self) -> usize{
+        let v = self.visit(  &'>', (1, 0));
+        let count = v.len();
+        for (from, to) in v {
+            self.grid[to] = '>';
+            self.grid[from] = '.';
+        }
+        count
+    }
+
+    fn step(&mut self) -> usize {
+        self.move_all_right() +
+        self.move_all_down()
+    }
+
+    fn move_until_stop(&mut self) -> usize {
+        let mut i = 0;
+        while self.step() > 0 {
+            //println!("{}\n\n", self);
+            i+=1;
+        }
+        i+1
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::{Output, Solution};
+    use super::Day25;
+
+    const EXAMPLE: &str = "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn test_example() {
+        let input = Day25::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("58", Day25::part_a(&input).to_string());
+        assert_eq!(Output::None, Day25::part_b(&input));
+    }
+}
@@ -0,0 +1,220 @@
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::parse_digits;
+use crate::solution::{Output, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = DiagnosticReport;
+
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        DiagnosticReport::parse(lines)
+    }
+
+    fn part_a(report: &Self::Input) -> Output {
+        product(report.gamma(), report.epsilon())
+    }
+
+    fn part_b(report: &Self::Input) -> Output {
+        product(report.oxygen_rating(), report.co2_rating())
+    }
+}
+
+impl Generate for Day3 {
+    /// Number of diagnostic numbers, at most 4096 because they have 12 bits and are unique so
+    /// that the bit criteria leave a single rating.
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers = (0..1 << 12).collect_vec();
+        rng.shuffle(&mut numbers);
+        numbers[..size.clamp(1, numbers.len())].iter()
+            .map(|n| format!("{:012b}\n", n))
+            .collect()
+    }
+}
+
+/// Binary numbers of the same width, at most 128 bits, each packed into an integer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    width: u32,
+    numbers: Vec<u128>,
+}
+
+impl DiagnosticReport {
+    pub const MAX_WIDTH: u32 = u128::BITS;
+
+    /// Reads one binary number per line, all with as many digits as the first one.
+    pub fn parse(lines: impl Iterator<Item=String>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let numbers = (1..).zip(lines)
+            .map(|(n, line)| {
+                let digits = parse_digits(n, &line, 2)?;
+                let width = *width.get_or_insert(digits.len());
+                if digits.len() != width {
+                    return Err(ParseError::invalid(n, &line, &line, &format!("{} binary digits", width)));
+                }
+                if digits.is_empty() || digits.len() > Self::MAX_WIDTH as usize {
+                    return Err(ParseError::invalid(n, &line, &line, &format!("1 to {} binary digits", Self::MAX_WIDTH)));
+                }
+                Ok(digits.iter().fold(0, |number, &digit| number << 1 | digit as u128))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(DiagnosticReport { width: width.unwrap_or(0) as u32, numbers })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn numbers(&self) -> &[u128] {
+        &self.numbers
+    }
+
+    /// How many of the numbers have a 1 in each column, starting with the most significant one.
+    pub fn ones(&self) -> Vec<usize> {
+        (0..self.width).rev()
+            .map(|bit| self.numbers.iter().filter(|&&n| n >> bit & 1 == 1).count())
+            .collect()
+    }
+
+    /// The most common bit of each column, 1 if both are equally common.
+    pub fn gamma(&self) -> u128 {
+        self.ones().iter().fold(0, |gamma, &ones| gamma << 1 | (2 * ones >= self.numbers.len()) as u128)
+    }
+
+    /// The least common bit of each column, the inverse of gamma.
+    pub fn epsilon(&self) -> u128 {
+        !self.gamma() & self.mask()
+    }
+
+    /// The number left when keeping those with the most common bit in each column, 1 on a tie.
+    pub fn oxygen_rating(&self) -> u128 {
+        self.rating(|ones, count| 2 * ones >= count)
+    }
+
+    /// The number left when keeping those with the least common bit in each column, 0 on a tie.
+    pub fn co2_rating(&self) -> u128 {
+        self.rating(|ones, count| 2 * ones < count)
+    }
+
+    /// Filters the numbers column by column, keeping those whose bit is 1 exactly if `criteria`
+    /// holds for the count of ones and of remaining numbers, until a single one is left. A column
+    /// whose bit all remaining numbers share filters none of them out.
+    fn rating(&self, criteria: fn(usize, usize) -> bool) -> u128 {
+        let mut remaining = self.numbers.clone();
+        for bit in (0..self.width).rev() {
+            if remaining.len() <= 1 {
+                break;
+            }
+            let ones = remaining.iter().filter(|&&n| n >> bit & 1 == 1).count();
+            if ones > 0 && ones < remaining.len() {
+                let keep = criteria(ones, remaining.len()) as u128;
+                remaining.retain(|&n| n >> bit & 1 == keep);
+            }
+        }
+        remaining.first().copied().unwrap_or(0)
+    }
+
+    fn mask(&self) -> u128 {
+        if self.width == 0 { 0 } else { u128::MAX >> (u128::BITS - self.width) }
+    }
+}
+
+/// The product of two ratings. Beyond 128 bits it is multiplied out in 64-bit limbs and given as
+/// its decimal digits.
+fn product(a: u128, b: u128) -> Output {
+    if let Some(product) = a.checked_mul(b) {
+        return product.into();
+    }
+    let limbs = |n: u128| [n as u64, (n >> 64) as u64];
+    // least significant limb first
+    let mut wide = [0u64; 4];
+    for (i, x) in limbs(a).into_iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in limbs(b).into_iter().enumerate() {
+            let sum = wide[i + j] as u128 + x as u128 * y as u128 + carry;
+            wide[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        wide[i + 2] = carry as u64;
+    }
+    // divides by 10^19 until nothing is left, yielding 19 decimal digits at a time
+    const CHUNK: u128 = 10u128.pow(19);
+    let mut chunks = vec![];
+    while wide.iter().any(|&limb| limb != 0) {
+        let mut remainder = 0u128;
+        for limb in wide.iter_mut().rev() {
+            let value = remainder << 64 | *limb as u128;
+            *limb = (value / CHUNK) as u64;
+            remainder = value % CHUNK;
+        }
+        chunks.push(remainder);
+    }
+    let (first, rest) = chunks.split_last().expect("the product is not zero");
+    Output::Text(format!("{}{}", first, rest.iter().rev().map(|chunk| format!("{:019}", chunk)).join("")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{product, DiagnosticReport, Day3};
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn test_example() {
+        let input = Day3::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("198", Day3::part_a(&input).to_string());
+        assert_eq!("230", Day3::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_shared_bit() {
+        let input = Day3::parse(example_lines("100\n101\n111")).unwrap();
+        assert_eq!("35", Day3::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_diagnostic_report() {
+        let report = Day3::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!(5, report.width());
+        assert_eq!(vec![7, 5, 8, 7, 5], report.ones());
+        assert_eq!((22, 9, 23, 10), (report.gamma(), report.epsilon(), report.oxygen_rating(), report.co2_rating()));
+
+        let wide = format!("1{}\n0{}\n1{}", "0".repeat(127), "1".repeat(127), "1".repeat(127));
+        let report = Day3::parse(example_lines(&wide)).unwrap();
+        assert_eq!(128, report.width());
+        assert_eq!(u128::MAX, report.gamma());
+        assert_eq!(0, report.epsilon());
+        assert_eq!(u128::MAX, report.oxygen_rating());
+        assert_eq!((1 << 127) - 1, report.co2_rating());
+        assert_eq!("57896044618658097711785492504343953926124568782438874324533730092808912502785", Day3::part_b(&report).to_string());
+
+        assert_eq!("<input>:2:1: expected 3 binary digits, found '10'", Day3::parse(example_lines("101\n10")).unwrap_err().to_string());
+        assert!(DiagnosticReport::parse(example_lines(&"1".repeat(129))).unwrap_err().to_string().contains("expected 1 to 128 binary digits"));
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!("198", product(22, 9).to_string());
+        assert_eq!("340282366920938463463374607431768211456", product(1 << 64, 1 << 64).to_string());
+        assert_eq!("1569275433846670190958947364679184625741050812417628438549", product((1 << 100) + 3, (1 << 90) + 7).to_string());
+        assert_eq!("115792089237316195423570985008687907852589419931798687112530834793049593217025", product(u128::MAX, u128::MAX).to_string());
+    }
+}
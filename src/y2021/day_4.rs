@@ -0,0 +1,340 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::iter;
+use itertools::Itertools;
+use tracing::{debug, trace};
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::Line;
+use crate::parse::{blocks, comma_list};
+use crate::solution::{Output, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = BingoGame;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part_a(game: &Self::Input) -> Output {
+        game.wins().next().map_or(Output::None, |win| win.score.into())
+    }
+
+    fn part_b(game: &Self::Input) -> Output {
+        game.wins().last().map_or(Output::None, |win| win.score.into())
+    }
+}
+
+impl Generate for Day4 {
+    /// Number of boards. All numbers up to 99 are drawn, so every board wins eventually.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers = (0..100).collect_vec();
+        rng.shuffle(&mut numbers);
+        let mut text = numbers.iter().join(",") + "\n";
+        for _ in 0..size.max(1) {
+            rng.shuffle(&mut numbers);
+            text.push('\n');
+            for row in numbers[..25].chunks(5) {
+                text += &(row.iter().map(|n| format!("{: >2}", n)).join(" ") + "\n");
+            }
+        }
+        text
+    }
+}
+
+/// A full row, column or, on square boards, diagonal of marked numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WinningLine {
+    Row(usize),
+    Column(usize),
+    /// From the top left to the bottom right corner.
+    Diagonal,
+    /// From the top right to the bottom left corner.
+    AntiDiagonal,
+}
+
+/// A board of distinct numbers with any number of rows and columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BingoBoard {
+    columns: usize,
+    numbers: Vec<usize>,
+    marked: Vec<bool>,
+    cells: HashMap<usize, usize>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    diagonal_marks: [usize; 2],
+    unmarked_sum: usize,
+    won: bool,
+}
+
+/// Why rows do not make a board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardError {
+    /// The row at this index is not as long as the first one.
+    RaggedRow(usize),
+    /// A number at this row and column is already on the board.
+    DuplicateNumber { number: usize, row: usize, column: usize },
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardError::RaggedRow(row) => write!(f, "row {} is not as long as the first one", row),
+            BoardError::DuplicateNumber { number, .. } => write!(f, "{} appears twice on the board", number),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+/// Numbers to draw for a set of boards, and whether diagonals count.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BingoGame {
+    pub numbers: Vec<usize>,
+    pub boards: Vec<BingoBoard>,
+    pub diagonals: bool,
+}
+
+/// A board winning on a drawn number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the board in the game.
+    pub board: usize,
+    /// Index of the number in the drawn numbers.
+    pub draw: usize,
+    pub number: usize,
+    pub line: WinningLine,
+    /// The sum of the unmarked numbers of the board times the winning number.
+    pub score: usize,
+}
+
+impl BingoBoard {
+    /// A board of the given rows, which must all be as long and contain distinct numbers.
+    pub fn new(rows: Vec<Vec<usize>>) -> Result<Self, BoardError> {
+        let columns = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != columns) {
+            return Err(BoardError::RaggedRow(row));
+        }
+        let numbers = rows.concat();
+        let mut cells = HashMap::new();
+        for (cell, &number) in numbers.iter().enumerate() {
+            if cells.insert(number, cell).is_some() {
+                return Err(BoardError::DuplicateNumber { number, row: cell / columns, column: cell % columns });
+            }
+        }
+        Ok(BingoBoard {
+            columns,
+            marked: vec![false; numbers.len()],
+            row_marks: vec![0; rows.len()],
+            column_marks: vec![0; columns],
+            diagonal_marks: [0; 2],
+            unmarked_sum: numbers.iter().sum(),
+            won: false,
+            numbers,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.row_marks.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.won
+    }
+
+    pub fn unmarked_sum(&self) -> usize {
+        self.unmarked_sum
+    }
+
+    /// Marks `number` if it is on the board, and returns the line that makes the board win with
+    /// it, preferring rows over columns over diagonals. A board wins only once.
+    pub fn mark(&mut self, number: usize, diagonals: bool) -> Option<WinningLine> {
+        let &cell = self.cells.get(&number)?;
+        if self.marked[cell] {
+            return None;
+        }
+        self.marked[cell] = true;
+        self.unmarked_sum -= number;
+        let (row, column) = (cell / self.columns, cell % self.columns);
+        self.row_marks[row] += 1;
+        self.column_marks[column] += 1;
+        let square = self.rows() == self.columns;
+        if square && row == column {
+            self.diagonal_marks[0] += 1;
+        }
+        if square && row + column + 1 == self.columns {
+            self.diagonal_marks[1] += 1;
+        }
+
+        let line = if self.row_marks[row] == self.columns {
+            WinningLine::Row(row)
+        } else if self.column_marks[column] == self.rows() {
+            WinningLine::Column(column)
+        } else if diagonals && square && row == column && self.diagonal_marks[0] == self.columns {
+            WinningLine::Diagonal
+        } else if diagonals && square && row + column + 1 == self.columns && self.diagonal_marks[1] == self.columns {
+            WinningLine::AntiDiagonal
+        } else {
+            return None;
+        };
+        if self.won {
+            return None;
+        }
+        self.won = true;
+        Some(line)
+    }
+}
+
+impl Display for BingoBoard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let width = self.numbers.iter().max().map_or(1, |n| n.to_string().len());
+        let rows = self.numbers.iter().zip(&self.marked)
+            .map(|(number, marked)| format!("{: >width$} {}", number, if *marked {"X"} else {"_"}))
+            .chunks(self.columns.max(1))
+            .into_iter()
+            .map(|mut row| row.join(" | "))
+            .join("\n");
+        write!(f, "{}", rows)
+    }
+}
+
+impl BingoGame {
+    pub fn new(numbers: Vec<usize>, boards: Vec<BingoBoard>) -> Self {
+        BingoGame { numbers, boards, diagonals: false }
+    }
+
+    /// Draws the numbers on copies of the boards and yields every win as it happens, in the order
+    /// of the boards for wins on the same number.
+    pub fn wins(&self) -> impl Iterator<Item=Win> + '_ {
+        debug!("{} numbers and {} boards", self.numbers.len(), self.boards.len());
+        let mut boards = self.boards.clone();
+        let mut pending = VecDeque::new();
+        let mut draws = self.numbers.iter().enumerate();
+        iter::from_fn(move || loop {
+            if let Some(win) = pending.pop_front() {
+                return Some(win);
+            }
+            let (draw, &number) = draws.next()?;
+            debug!("drawing number {}", number);
+            for (i, board) in boards.iter_mut().enumerate() {
+                if let Some(line) = board.mark(number, self.diagonals) {
+                    debug!("board {} wins with {:?}", i, line);
+                    pending.push_back(Win { board: i, draw, number, line, score: board.unmarked_sum() * number });
+                }
+                trace!("\n{}", board);
+            }
+        })
+    }
+}
+
+fn parse_input(text: &str) -> Result<BingoGame, ParseError> {
+    let mut blocks = blocks(text);
+    let numbers = match blocks.next().as_deref() {
+        Some([line]) => comma_list(*line)?,
+        Some([_, line, ..]) => return Err(line.invalid(line.text, "a blank line before the first board")),
+        _ => return Err(ParseError::missing(1, "", "the drawn numbers")),
+    };
+    let boards = blocks.map(|block| parse_board(&block)).collect::<Result<_, _>>()?;
+    Ok(BingoGame::new(numbers, boards))
+}
+
+/// A board with as many numbers in each row as in the first one.
+fn parse_board(block: &[Line]) -> Result<BingoBoard, ParseError> {
+    let rows = block.iter()
+        .map(|line| line.fields(' ').map(|field| line.int(field)).collect::<Result<Vec<_>, _>>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let columns = rows[0].len();
+    BingoBoard::new(rows).map_err(|e| match e {
+        BoardError::RaggedRow(row) => match block[row].fields(' ').nth(columns) {
+            Some(extra) => block[row].invalid(extra, &format!("{} numbers per board row", columns)),
+            None => block[row].missing(&format!("{} numbers per board row", columns)),
+        },
+        BoardError::DuplicateNumber { row, column, .. } => {
+            let field = block[row].fields(' ').nth(column).unwrap_or(block[row].text);
+            block[row].invalid(field, "a number that is not on the board yet")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{BingoBoard, BingoGame, BoardError, Day4, Win, WinningLine};
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn test_example() {
+        let input = Day4::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("4512", Day4::part_a(&input).to_string());
+        assert_eq!("1924", Day4::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_wins() {
+        let game = Day4::parse(example_lines(EXAMPLE)).unwrap();
+        let wins = game.wins().collect::<Vec<_>>();
+        assert_eq!(vec![2, 0, 1], wins.iter().map(|win| win.board).collect::<Vec<_>>());
+        assert_eq!(Win { board: 2, draw: 11, number: 24, line: WinningLine::Row(0), score: 4512 }, wins[0]);
+        assert_eq!(Win { board: 1, draw: 14, number: 13, line: WinningLine::Column(2), score: 1924 }, wins[2]);
+
+        let board = BingoBoard::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        let mut game = BingoGame::new(vec![3, 5, 1, 7], vec![board]);
+        assert_eq!(None, game.wins().next());
+        game.diagonals = true;
+        assert_eq!(Some(WinningLine::AntiDiagonal), game.wins().next().map(|win| win.line));
+        assert_eq!("1 _ | 2 _ | 3 X\n4 _ | 5 X | 6 _\n7 _ | 8 _ | 9 _", {
+            let mut board = game.boards[0].clone();
+            board.mark(3, false);
+            board.mark(5, false);
+            board.to_string()
+        });
+
+        let game = Day4::parse(example_lines("2,1,4\n\n1 2\n3 4\n5 6")).unwrap();
+        assert_eq!((3, 2), (game.boards[0].rows(), game.boards[0].columns()));
+        assert_eq!(Some(Win { board: 0, draw: 1, number: 1, line: WinningLine::Row(0), score: 18 }), game.wins().next());
+
+        assert_eq!("<input>:4:4: missing 3 numbers per board row", Day4::parse(example_lines("1\n\n1 2 3\n4 5")).unwrap_err().to_string());
+        assert_eq!("<input>:4:5: expected 2 numbers per board row, found '6'", Day4::parse(example_lines("1\n\n1 2\n4 5 6")).unwrap_err().to_string());
+        assert_eq!("<input>:4:3: expected a number that is not on the board yet, found '2'", Day4::parse(example_lines("1\n\n1 2\n4 2")).unwrap_err().to_string());
+
+        assert_eq!(Err(BoardError::RaggedRow(1)), BingoBoard::new(vec![vec![1, 2], vec![3]]));
+        assert_eq!(Err(BoardError::DuplicateNumber { number: 2, row: 1, column: 0 }), BingoBoard::new(vec![vec![1, 2], vec![2, 3]]));
+    }
+
+    #[test]
+    fn test_no_win() {
+        let game = Day4::parse(example_lines("1\n\n2 3\n4 5")).unwrap();
+        assert_eq!("-", Day4::part_a(&game).to_string());
+        assert_eq!("-", Day4::part_b(&game).to_string());
+    }
+}
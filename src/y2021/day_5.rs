@@ -0,0 +1,333 @@
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input;
+use crate::parse::segment;
+use crate::solution::{Output, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        read_input(text)
+    }
+
+    fn part_a(lines: &Self::Input) -> Output {
+        VentMap::new(lines.iter().filter(|line| line.is_axis_aligned())).overlap_count().into()
+    }
+
+    fn part_b(lines: &Self::Input) -> Output {
+        VentMap::new(lines).overlap_count().into()
+    }
+}
+
+impl Generate for Day5 {
+    /// Number of lines of vents, each horizontal, vertical or diagonal at 45 degrees.
+    const SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const MAX: i64 = 990;
+        (0..size.max(1))
+            .map(|_| {
+                let length = rng.range(1..=MAX / 2);
+                let (x, y) = (rng.range(0..=MAX - length), rng.range(0..=MAX - length));
+                let (dx, dy) = *rng.choose(&[(length, 0), (0, length), (length, length)]);
+                let (mut from, mut to) = ((x, y), (x + dx, y + dy));
+                if rng.chance(0.5) {
+                    (from.1, to.1) = (to.1, from.1);
+                }
+                if rng.chance(0.5) {
+                    (from, to) = (to, from);
+                }
+                format!("{},{} -> {},{}\n", from.0, from.1, to.0, to.1)
+            })
+            .collect()
+    }
+}
+
+/// Coordinates of the points of lines are at most this far from zero, which keeps the arithmetic
+/// of crossing lines in range.
+pub const MAX_COORDINATE: i64 = (1 << 62) - 1;
+
+/// A point of the map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A line of vents between two points, which may have any slope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line(pub Point, pub Point);
+
+/// Consecutive lattice points of a line that lie on the same number of lines, at least two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub from: Point,
+    pub step: (i64, i64),
+    pub points: u128,
+    pub lines: usize,
+}
+
+/// Where lines of vents overlap. The overlaps are found by intersecting the lines with each other,
+/// so neither the length of the lines nor their coordinates matter, and stored as runs of points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VentMap {
+    lines: Vec<Line>,
+    overlaps: Vec<Overlap>,
+}
+
+/// The lattice points of a line as `from + t * step` for `t` in `0..=steps`, with `step` as short
+/// as possible.
+struct Lattice {
+    from: (i128, i128),
+    step: (i128, i128),
+    steps: i128,
+}
+
+impl Line {
+    pub fn is_axis_aligned(&self) -> bool {
+        self.0.x == self.1.x || self.0.y == self.1.y
+    }
+
+    /// The points on the line whose coordinates are both integers, from the start to the end.
+    /// Lines at 45 degrees cover a point in every row and column, steeper or flatter ones skip
+    /// some of them.
+    pub fn points(&self) -> impl Iterator<Item=Point> {
+        let lattice = Lattice::new(self);
+        (0..=lattice.steps).map(move |t| lattice.at(t))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        Lattice::new(self).covers(&Lattice::new(&Line(point, point))).is_some()
+    }
+}
+
+impl Overlap {
+    pub fn points(&self) -> impl Iterator<Item=Point> + '_ {
+        let points = i64::try_from(self.points).expect("lines within MAX_COORDINATE have fewer than 2^63 points");
+        (0..points).map(|i| Point { x: self.from.x + self.step.0 * i, y: self.from.y + self.step.1 * i })
+    }
+}
+
+impl VentMap {
+    pub fn new<'a>(lines: impl IntoIterator<Item=&'a Line>) -> Self {
+        let lines = lines.into_iter().copied().collect::<Vec<_>>();
+        let lattices = lines.iter().map(Lattice::new).collect::<Vec<_>>();
+        let overlaps = (0..lines.len())
+            .flat_map(|i| overlaps_on(&lattices, i))
+            .collect();
+        VentMap { lines, overlaps }
+    }
+
+    /// How many lines cross `point`.
+    pub fn count(&self, point: Point) -> usize {
+        self.lines.iter().filter(|line| line.contains(point)).count()
+    }
+
+    /// The runs of overlapping points, each point in exactly one of them.
+    pub fn overlap_runs(&self) -> &[Overlap] {
+        &self.overlaps
+    }
+
+    /// The number of points where at least two lines overlap.
+    pub fn overlap_count(&self) -> u128 {
+        self.overlaps.iter().map(|overlap| overlap.points).sum()
+    }
+
+    /// Every point where at least two lines overlap and how many do, ordered by x and then y.
+    pub fn overlaps(&self) -> Vec<(Point, usize)> {
+        let mut overlaps = self.overlaps.iter()
+            .flat_map(|overlap| overlap.points().map(|point| (point, overlap.lines)))
+            .collect::<Vec<_>>();
+        overlaps.sort_unstable();
+        overlaps
+    }
+}
+
+impl Lattice {
+    fn new(line: &Line) -> Self {
+        let Line(from, to) = *line;
+        let (dx, dy) = (to.x as i128 - from.x as i128, to.y as i128 - from.y as i128);
+        let steps = gcd(dx.unsigned_abs(), dy.unsigned_abs()) as i128;
+        Lattice {
+            from: (from.x as i128, from.y as i128),
+            step: (dx / steps.max(1), dy / steps.max(1)),
+            steps,
+        }
+    }
+
+    fn at(&self, t: i128) -> Point {
+        Point { x: (self.from.0 + self.step.0 * t) as i64, y: (self.from.1 + self.step.1 * t) as i64 }
+    }
+
+    /// The range of `t` of the points of this line that `other` covers as well.
+    fn covers(&self, other: &Lattice) -> Option<(i128, i128)> {
+        let cross = |a: (i128, i128), b: (i128, i128)| a.0 * b.1 - a.1 * b.0;
+        let dot = |a: (i128, i128), b: (i128, i128)| a.0 * b.0 + a.1 * b.1;
+        if self.steps == 0 {
+            // a single point is covered if it is one of the other line's points
+            return if other.steps == 0 { (self.from == other.from).then_some((0, 0)) } else { other.covers(self).map(|_| (0, 0)) };
+        }
+        let d = (other.from.0 - self.from.0, other.from.1 - self.from.1);
+        let (lo, hi) = if other.steps == 0 || cross(self.step, other.step) == 0 {
+            // parallel lines overlap in a range of points if they lie on the same line
+            if cross(d, self.step) != 0 || dot(d, self.step) % dot(self.step, self.step) != 0 {
+                return None;
+            }
+            let start = dot(d, self.step) / dot(self.step, self.step);
+            let end = start + other.steps * dot(other.step, self.step) / dot(self.step, self.step);
+            (start.min(end), start.max(end))
+        } else {
+            // crossing lines share at most the point where they cross
+            let denominator = cross(self.step, other.step);
+            let (t, u) = (cross(d, other.step), cross(d, self.step));
+            if t % denominator != 0 || u % denominator != 0 || !(0..=other.steps).contains(&(u / denominator)) {
+                return None;
+            }
+            (t / denominator, t / denominator)
+        };
+        let (lo, hi) = (lo.max(0), hi.min(self.steps));
+        (lo <= hi).then_some((lo, hi))
+    }
+}
+
+/// The overlaps on line `i` that are not on any line before it, found by sweeping along it.
+fn overlaps_on(lattices: &[Lattice], i: usize) -> Vec<Overlap> {
+    let line = &lattices[i];
+    let mut events = vec![];
+    for (j, other) in lattices.iter().enumerate().filter(|&(j, _)| j != i) {
+        if let Some((lo, hi)) = line.covers(other) {
+            let earlier = (j < i) as isize;
+            events.push((lo, 1, earlier));
+            events.push((hi + 1, -1, -earlier));
+        }
+    }
+    events.sort_unstable();
+    let mut overlaps = vec![];
+    let (mut others, mut earlier) = (0, 0);
+    for (k, &(t, delta, delta_earlier)) in events.iter().enumerate() {
+        others += delta;
+        earlier += delta_earlier;
+        let Some(&(next, _, _)) = events.get(k + 1) else { break };
+        if next > t && others > 0 && earlier == 0 {
+            overlaps.push(Overlap {
+                from: line.at(t),
+                step: (line.step.0 as i64, line.step.1 as i64),
+                points: (next - t) as u128,
+                lines: others as usize + 1,
+            });
+        }
+    }
+    overlaps
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn read_input(text: &str) -> Result<Vec<Line>, ParseError> {
+    input::lines(text)
+        .map(|line| {
+            let ([x1, y1], [x2, y2]) = segment(line)?;
+            // the coordinates are the tokens of "x1,y1 -> x2,y2" apart from the arrow
+            let tokens = line.text.split([',', ' ']).filter(|&token| token != "->");
+            if let Some((token, _)) = tokens.zip([x1, y1, x2, y2]).find(|&(_, c)| !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&c)) {
+                return Err(line.invalid(token, "a coordinate within ±(2^62 - 1)"));
+            }
+            Ok(Line(Point { x: x1, y: y1 }, Point { x: x2, y: y2 }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::generator::Rng;
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::{Day5, Line, MAX_COORDINATE, Point, VentMap};
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_example() {
+        let input = Day5::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("5", Day5::part_a(&input).to_string());
+        assert_eq!("12", Day5::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_vent_map() {
+        let point = |x, y| Point { x, y };
+        let lines = Day5::parse(example_lines("0,0 -> 6,3\n-3,-1 -> 9,3\n4,2 -> 4,2\n-9000000000000,5 -> 9000000000000,-5")).unwrap();
+        assert_eq!(vec![point(0, 0), point(2, 1), point(4, 2), point(6, 3)], lines[0].points().collect::<Vec<_>>());
+        assert_eq!(vec![point(-3, -1), point(0, 0), point(3, 1), point(6, 2), point(9, 3)], lines[1].points().collect::<Vec<_>>());
+        assert_eq!(vec![point(4, 2)], lines[2].points().collect::<Vec<_>>());
+        assert_eq!(11, lines[3].points().count());
+
+        let map = VentMap::new(&lines);
+        assert_eq!(vec![(point(0, 0), 3), (point(4, 2), 2)], map.overlaps());
+        assert_eq!(1, map.count(point(9000000000000, -5)));
+        assert_eq!(0, map.count(point(1, 0)));
+
+        let example = Day5::parse(example_lines(EXAMPLE)).unwrap();
+        let map = VentMap::new(example.iter().filter(|line: &&Line| line.is_axis_aligned()));
+        assert_eq!(vec![(point(0, 9), 2), (point(1, 9), 2), (point(2, 9), 2), (point(3, 4), 2), (point(7, 4), 2)], map.overlaps());
+    }
+
+    #[test]
+    fn test_long_lines() {
+        let crossing = Day5::parse(example_lines("0,0 -> 2000000000,0\n5,-5 -> 5,5")).unwrap();
+        assert_eq!("1", Day5::part_a(&crossing).to_string());
+        assert_eq!(vec![(Point { x: 5, y: 0 }, 2)], VentMap::new(&crossing).overlaps());
+
+        let collinear = Day5::parse(example_lines("0,0 -> 2000000000,0\n3000000000,0 -> 1000000000,0\n-7,-7 -> 7,7")).unwrap();
+        assert_eq!("1000000001", Day5::part_a(&collinear).to_string());
+        assert_eq!("1000000002", Day5::part_b(&collinear).to_string());
+    }
+
+    #[test]
+    fn test_coordinate_range() {
+        let crossing = "-9223372036854775807,-9223372036854775807 -> 9223372036854775807,9223372036854775806\n\
+                        9223372036854775807,-9223372036854775807 -> -9223372036854775807,9223372036854775806";
+        assert_eq!("<input>:1:1: expected a coordinate within ±(2^62 - 1), found '-9223372036854775807'",
+                   Day5::parse(example_lines(crossing)).unwrap_err().to_string());
+
+        let (lo, hi) = (-MAX_COORDINATE, MAX_COORDINATE);
+        let text = format!("{lo},{lo} -> {hi},{hi}\n{hi},{lo} -> {lo},{hi}\n{lo},{hi} -> {hi},{hi}\n{hi},{hi} -> {lo},{hi}");
+        let lines = Day5::parse(example_lines(&text)).unwrap();
+        assert_eq!("9223372036854775807", Day5::part_a(&lines).to_string());
+        assert_eq!("9223372036854775808", Day5::part_b(&lines).to_string());
+        let map = VentMap::new(&lines[..2]);
+        assert_eq!(vec![(Point { x: 0, y: 0 }, 2)], map.overlaps());
+        assert_eq!(3, VentMap::new(&lines).count(Point { x: hi, y: hi }));
+    }
+
+    #[test]
+    fn test_against_rasterized() {
+        let mut rng = Rng::new(5);
+        for _ in 0..200 {
+            let lines: Vec<_> = (0..6).map(|_| {
+                let mut point = || Point { x: rng.range(-6..=6), y: rng.range(-6..=6) };
+                Line(point(), point())
+            }).collect();
+            let mut counts = HashMap::new();
+            lines.iter().flat_map(Line::points).for_each(|p| *counts.entry(p).or_insert(0) += 1);
+            let mut expected: Vec<_> = counts.into_iter().filter(|&(_, n)| n > 1).collect();
+            expected.sort();
+            assert_eq!(expected, VentMap::new(&lines).overlaps(), "{:?}", lines);
+        }
+    }
+}
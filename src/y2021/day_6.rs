@@ -0,0 +1,102 @@
+use crate::error::{parse_token, ParseError};
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+
+    fn parse(mut lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        read_timers(&mut lines)
+    }
+
+    fn part_a(values: &Self::Input) -> Output {
+        task(values, 80).into()
+    }
+
+    fn part_b(values: &Self::Input) -> Output {
+        task(values, 256).into()
+    }
+}
+
+impl Generate for Day6 {
+    /// Number of lanternfish.
+    const SIZE: usize = 300;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| rng.range(1..=5).to_string()).collect::<Vec<_>>().join(",") + "\n"
+    }
+}
+
+fn task(values: &[usize], days: usize) -> usize {
+    let mut gens: Vec<Generation> = vec![];
+    (0..9).for_each(|i| gens.push((i, 0)));
+    values.iter().for_each(|v| gens[*v].1+=1);
+    (0..days).for_each(|_|next_cycle(&mut gens));
+    gens.iter().map(|g|g.1).sum()
+}
+
+fn next_cycle(gen: &mut [Generation]) {
+    gen.iter_mut().skip(1).for_each(|g|g.0-=1);
+    gen[7].1 += gen[0].1;
+    gen[0].0 = 8;
+    gen.sort_by_key(|g| g.0);
+}
+
+type Generation = (usize, usize);
+
+fn read_timers(lines: &mut impl Iterator<Item=String>) -> Result<Vec<usize>, ParseError> {
+    let line = lines.next().ok_or_else(|| ParseError::missing(1, "", "a comma separated list"))?;
+    line.split(',')
+        .map(|token| match parse_token(1, &line, token)? {
+            timer @ 0..=8 => Ok(timer),
+            _ => Err(ParseError::invalid(1, &line, token, "a timer from 0 to 8")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use crate::input::{example_lines, parse_example};
+    use crate::solution::Solution;
+    use super::{task, Day6};
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2";
+
+    #[test]
+    fn test_example() {
+        let input = Day6::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("5934", Day6::part_a(&input).to_string());
+        assert_eq!("26984457539", Day6::part_b(&input).to_string());
+    }
+
+    #[test]
+    fn test_timer_range() {
+        assert_eq!("<input>:1:3: expected a timer from 0 to 8, found '9'", Day6::parse(example_lines("3,9")).unwrap_err().to_string());
+        assert_eq!("<input>:1:1: expected usize, found '-1'", Day6::parse(example_lines("-1,2")).unwrap_err().to_string());
+    }
+
+    /// Keeps a timer for each fish.
+    fn simulate_fish(timers: &[usize], days: usize) -> usize {
+        let mut fish = timers.to_vec();
+        for _ in 0..days {
+            let spawned = fish.iter().filter(|&&timer| timer == 0).count();
+            fish.iter_mut().for_each(|timer| *timer = if *timer == 0 { 6 } else { *timer - 1 });
+            fish.extend(std::iter::repeat_n(8, spawned));
+        }
+        fish.len()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_fish_simulation(timers in vec(0..=8usize, 1..10), days in 0..60usize) {
+            let input = parse_example::<Day6>([timers.iter().join(",")]);
+            prop_assert_eq!(simulate_fish(&timers, days), task(&input, days));
+        }
+    }
+}
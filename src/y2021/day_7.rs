@@ -0,0 +1,70 @@
+use crate::input::read_usize_vec;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::solution::{Output, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<usize>;
+
+    fn parse(mut lines: impl Iterator<Item=String>) -> Result<Self::Input, ParseError> {
+        read_usize_vec(&mut lines)
+    }
+
+    fn part_a(positions: &Self::Input) -> Output {
+        task(positions, false).into()
+    }
+
+    fn part_b(positions: &Self::Input) -> Output {
+        task(positions, true).into()
+    }
+}
+
+impl Generate for Day7 {
+    /// Number of crabs.
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // most crabs are close to the start
+        (0..size.max(1))
+            .map(|_| {
+                let max = rng.range(0..=1999);
+                rng.range(0..=max).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(",") + "\n"
+    }
+}
+
+fn task(positions: &[usize], increasing_fuel: bool) -> usize {
+    let min = *positions.iter().min().expect("no positions");
+    let max = positions.iter().max().expect("no positions")+1;
+
+    (min..max).map(|i| fuel_to_target(positions, i, increasing_fuel))
+        .min().expect("no positions?")
+}
+
+fn fuel_to_target(current: &[usize], target: usize, increasing_fuel: bool) -> usize{
+    current.iter().map(|pos| {
+        let steps = pos.abs_diff(target);
+        if increasing_fuel {(1..steps+1).sum::<usize>()} else { steps }
+    }).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day7;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_example() {
+        let input = Day7::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("37", Day7::part_a(&input).to_string());
+        assert_eq!("168", Day7::part_b(&input).to_string());
+    }
+}
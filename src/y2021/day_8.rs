@@ -0,0 +1,145 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::input::{lines, Line};
+use crate::parse::{list, split_pair};
+use crate::solution::{Output, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Note>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        read_input(text)
+    }
+
+    fn part_a(notes: &Self::Input) -> Output {
+        notes.iter().flat_map(|Note{signals: _, outputs: o}| o).map(|s|s.len())
+            .filter(|length| [2, 3, 4, 7].contains(length))
+            .count().into()
+    }
+
+    fn part_b(notes: &Self::Input) -> Output {
+        notes.iter()
+            .map(|note| (note, analyze_signals(note)))
+            .map(|(note, decoder)|
+                note.outputs.iter().map(|o| decoder.get(o).expect("decoder incomplete"))
+                    .join(""))
+            .map(|s| s.parse::<usize>().expect("parsing decoded value failed"))
+            .sum::<usize>().into()
+    }
+}
+
+impl Generate for Day8 {
+    /// Number of entries.
+    const SIZE: usize = 200;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut wires = "abcdefg".chars().collect_vec();
+                rng.shuffle(&mut wires);
+                let mut digits = (0..10).collect_vec();
+                rng.shuffle(&mut digits);
+                let patterns = digits.iter().map(|&digit| scrambled(rng, &wires, digit)).join(" ");
+                let outputs = (0..4).map(|_| {
+                    let digit = rng.below(10);
+                    scrambled(rng, &wires, digit)
+                }).join(" ");
+                format!("{} | {}\n", patterns, outputs)
+            })
+            .collect()
+    }
+}
+
+const SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+/// The segments of `digit` with mixed up `wires`, in random order.
+fn scrambled(rng: &mut Rng, wires: &[char], digit: usize) -> String {
+    let mut segments = SEGMENTS[digit].chars().map(|s| wires[s as usize - 'a' as usize]).collect_vec();
+    rng.shuffle(&mut segments);
+    segments.into_iter().collect()
+}
+
+
+fn analyze_signals(note: &Note) -> HashMap<String, char> {
+    let signals = &note.signals;
+    let digit_1 = signals.iter().find(|s| s.len() == 2).expect("not found");
+    let digit_4 = signals.iter().find(|s| s.len() == 4).expect("not found");
+    let digit_7 = signals.iter().find(|s| s.len() == 3).expect("not found");
+    let digit_8 = signals.iter().find(|s| s.len() == 7).expect("not found");
+    let digit_2_3_5 = signals.iter().filter(|s| s.len() == 5).collect_vec();
+    let digit_6_9_0 = signals.iter().filter(|s| s.len() == 6).collect_vec();
+    let digit_3 = *digit_2_3_5.iter().find(|d| (**d - digit_1).len() == 3).expect("not found");
+    let digit_2 = *digit_2_3_5.iter().find(|d| (**d - digit_4).len() == 3).expect("not found");
+    let digit_5 = *digit_2_3_5.iter().find(|d| (**d - digit_2).len() == 2).expect("not found");
+    let digit_9 = *digit_6_9_0.iter().find(|d| (**d - digit_4).len() == 2).expect("not found");
+    let digit_6 = *digit_6_9_0.iter().find(|d| (**d - digit_7).len() == 4).expect("not found");
+    let digit_0 = *digit_6_9_0.iter().find(|d|**d != digit_6 && **d != digit_9).expect("not found");
+
+    let mut decoder = HashMap::new();
+    decoder.insert(digit_0.iter().sorted().join(""), '0');
+    decoder.insert(digit_1.iter().sorted().join(""), '1');
+    decoder.insert(digit_2.iter().sorted().join(""), '2');
+    decoder.insert(digit_3.iter().sorted().join(""), '3');
+    decoder.insert(digit_4.iter().sorted().join(""), '4');
+    decoder.insert(digit_5.iter().sorted().join(""), '5');
+    decoder.insert(digit_6.iter().sorted().join(""), '6');
+    decoder.insert(digit_7.iter().sorted().join(""), '7');
+    decoder.insert(digit_8.iter().sorted().join(""), '8');
+    decoder.insert(digit_9.iter().sorted().join(""), '9');
+    //println!("decoder: {:?}", &decoder);
+    assert_eq!(decoder.len(), 10);
+    decoder
+}
+
+
+type Word = HashSet<char>;
+pub struct Note{signals: Vec<Word>, outputs: Vec<String>}
+
+fn read_input(text: &str) -> Result<Vec<Note>, ParseError> {
+    lines(text)
+        .map(|line| {
+            let (signals, outputs) = split_pair(line, line.text, " | ", "output digits")?;
+            Ok(Note {
+                signals: parse_patterns(line, signals)?.iter().map(|s|HashSet::from_iter(s.chars())).collect(),
+                outputs: parse_patterns(line, outputs)?.iter().map(|s|s.chars().sorted().join("")).collect()
+            })
+        })
+        .collect()
+}
+
+fn parse_patterns<'a>(line: Line, patterns: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    list(patterns, " ", |pattern| match !pattern.is_empty() && pattern.chars().all(|c| ('a'..='g').contains(&c)) {
+        true => Ok(pattern),
+        false => Err(line.invalid(pattern, "segments a-g")),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day8;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    #[test]
+    fn test_example() {
+        let input = Day8::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("26", Day8::part_a(&input).to_string());
+        assert_eq!("61229", Day8::part_b(&input).to_string());
+    }
+}
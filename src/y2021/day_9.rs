@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::error::ParseError;
+use crate::generator::{Generate, Rng};
+use crate::grid::{Grid, Position};
+use crate::input::lines;
+use crate::parse::digit_grid;
+use crate::solution::{Output, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<usize>;
+
+    fn parse_text(text: &str) -> Result<Self::Input, ParseError> {
+        digit_grid(lines(text))
+    }
+
+    fn part_a(grid: &Self::Input) -> Output {
+        find_low_points(grid).iter().map(|&p| grid[p] + 1).sum::<usize>().into()
+    }
+
+    fn part_b(grid: &Self::Input) -> Output {
+        let low_points = find_low_points(grid);
+        low_points.into_iter().map(|p| {
+            let mut checked = HashSet::new();
+            checked.insert(p);
+            let unchecked = larger_neighbours(grid, p).into_iter()
+                .filter(|&n| grid[n] < 9).collect();
+            basin_size(grid, checked, unchecked)
+        }).sorted().rev().take(3).product::<usize>().into()
+    }
+}
+
+impl Generate for Day9 {
+    /// Side of the square height map.
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // basins slope down to random low points and are separated by ridges of 9s
+        let size = size.max(1);
+        let low_points = (0..(size * size / 50).max(1)).map(|_| (rng.below(size), rng.below(size))).collect_vec();
+        let nearest = Grid::from_fn(size, size, |(x, y)| low_points.iter().enumerate()
+            .map(|(basin, &(lx, ly))| (x.abs_diff(lx) + y.abs_diff(ly), basin))
+            .min()
+            .expect("at least one low point"));
+        let heights = Grid::from_fn(size, size, |p| {
+            let (distance, basin) = nearest[p];
+            if nearest.neighbours_4(p).any(|n| nearest[n].1 < basin) { 9 } else { distance.min(8) }
+        });
+        heights.to_string() + "\n"
+    }
+}
+
+fn basin_size(grid: &Grid<usize>, checked: HashSet<Position>, unchecked: HashSet<Position>) -> usize {
+    let new_unchecked = unchecked.iter()
+        .flat_map(|&field| {
+            larger_neighbours(grid, field).into_iter()
+                .filter(|f| !checked.contains(f) && grid[*f] < 9)
+                .collect::<HashSet<_>>()
+        }).collect::<HashSet<_>>();
+
+    let new_checked = checked.union(&unchecked).copied().collect::<HashSet<_>>();
+
+    if new_unchecked.is_empty() {
+        new_checked.len()
+    } else {
+        basin_size(grid, new_checked, new_unchecked)
+    }
+}
+
+fn find_low_points(grid: &Grid<usize>) -> Vec<Position> {
+    grid.iter()
+        .filter(|&(p, center)| grid.neighbours_4(p).all(|n| grid[n] > *center))
+        .map(|(p, _)| p)
+        .collect()
+}
+
+fn larger_neighbours(grid: &Grid<usize>, p: Position) -> HashSet<Position> {
+    grid.neighbours_4(p).filter(|&n| grid[n] > grid[p]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example_lines;
+    use crate::solution::Solution;
+    use super::Day9;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_example() {
+        let input = Day9::parse(example_lines(EXAMPLE)).unwrap();
+        assert_eq!("15", Day9::part_a(&input).to_string());
+        assert_eq!("1134", Day9::part_b(&input).to_string());
+    }
+}